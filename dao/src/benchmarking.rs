@@ -22,7 +22,6 @@ use super::*;
 use crate::Pallet as PalletDao;
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller, vec};
 use frame_system::RawOrigin;
-use frame_support::sp_runtime::traits::Hash;
 
 const SEED: u32 = 1;
 
@@ -31,6 +30,15 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+// Helper function to create a task owned by the caller, only the founder's tasks can be added
+fn create_task<T: Config>(caller: T::AccountId) -> T::Hash {
+	let origin: <T as frame_system::Config>::Origin = RawOrigin::Signed(caller.clone()).into();
+	let _ = pallet_profile::Pallet::<T>::create_profile(origin.clone(), Vec::new(), Vec::new());
+	let deadline = frame_system::Pallet::<T>::block_number() + 10u32.into();
+	let _ = pallet_task::Pallet::<T>::create_task(origin, vec![0u8], Vec::new(), Default::default(), deadline, Vec::new());
+	*pallet_task::Pallet::<T>::tasks_owned(&caller).last().expect("task is created")
+}
+
 benchmarks! {
 	benchmark_name {
 		/* setup initial state */
@@ -138,6 +146,25 @@ benchmarks! {
 		assert_last_event::<T>(Event::<T>::OrganizationDissolved( caller, name).into())
 	}
 
+	set_commission {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+
+		let s in 1 .. u8::MAX.into();
+		let name = vec![0u8, s as u8];
+		let commission = Some(T::MaxCommission::get());
+
+		// Create organization before setting its commission
+		let _ = PalletDao::<T>::create_organization(RawOrigin::Signed(caller.clone()).into(), name.clone());
+
+	}: set_commission(RawOrigin::Signed(caller.clone()), name.clone(), commission)
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::CommissionSet(caller, name, commission).into())
+	}
+
 	add_members {
 		/* setup initial state */
 		let caller: T::AccountId = whitelisted_caller();
@@ -166,9 +193,8 @@ benchmarks! {
 		let s in 1 .. u8::MAX.into();
 		let name = vec![0u8, s as u8];
 		
-		// Create hash
-		let task_hash_h256 = "task hash";
-		let hash = T::Hashing::hash_of(&task_hash_h256);
+		// Create a task of the founder
+		let hash = create_task::<T>(caller.clone());
 
 		// Create organization before adding members to it
 		let _ = PalletDao::<T>::create_organization(RawOrigin::Signed(caller.clone()).into(), name.clone());
@@ -195,13 +221,13 @@ benchmarks! {
 		// Create organization before adding members to it
 		let _ = PalletDao::<T>::create_organization(RawOrigin::Signed(caller.clone()).into(), name.clone());
 		let _ = PalletDao::<T>::add_members(RawOrigin::Signed(caller.clone()).into(), name.clone(), account.clone());
-		assert_eq!(PalletDao::<T>::organization(name.clone()).len(), 2);
+		assert_eq!(PalletDao::<T>::organization(PalletDao::<T>::bounded_org_name(&name).unwrap()).len(), 2);
 		
 	}: remove_members(RawOrigin::Signed(caller.clone()), name.clone(), account.clone() )
 		/* the code to be benchmarked */
 	verify {
 		/* verifying final state */
-		assert_eq!(PalletDao::<T>::organization(name.clone()).len(), 1);
+		assert_eq!(PalletDao::<T>::organization(PalletDao::<T>::bounded_org_name(&name).unwrap()).len(), 1);
 		assert_last_event::<T>(Event::<T>::MemberRemoved (caller, account ).into());
 	}
 
@@ -212,9 +238,8 @@ benchmarks! {
 		let s in 1 .. u8::MAX.into();
 		let name = vec![0u8, s as u8];
 		
-		// Create hash
-		let task_hash_h256 = "task hash";
-		let hash = T::Hashing::hash_of(&task_hash_h256);
+		// Create a task of the founder
+		let hash = create_task::<T>(caller.clone());
 
 		// Create organization 
 		let _ = PalletDao::<T>::create_organization(RawOrigin::Signed(caller.clone()).into(), name.clone());
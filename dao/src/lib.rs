@@ -60,10 +60,23 @@
 //! 
 //! - `dissolve_organization` - Function used for a visionary to dissolve his organization. 
 //! 
-//! - `add_tasks` - Function used for a visionary to add tasks to his organization.
+//! - `add_tasks` - Function used for a visionary to add tasks to his organization. Only tasks
+//! the visionary created can be added, and a task belongs to at most one organization.
 //! 
 //! - `remove_tasks` - Function used for a visionary to remove tasks from his organization.
 //!
+//! - `set_commission` - Function used for a visionary to override the commission taken from
+//! payouts of the organization's tasks, up to the maximum commission.
+//!
+//! Members of an organization arbitrate disputes about the organization's tasks. Commissions
+//! on payouts of the organization's tasks are paid to its treasury account. Tasks leave their
+//! organization once they are closed, expire or are removed from the task pallet.
+//!
+//! Runtime upgrades to storage version 1 re-key the organization's tasks together with
+//! the nonce based task id migration of the task pallet. Storage version 2 bounds visions,
+//! organization names and the member and task lists of organizations. Storage version 3
+//! indexes the organization of each task and drops tasks the founder did not create.
+//!
 //! ## Related Modules
 //!
 
@@ -86,11 +99,27 @@ mod benchmarking;
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+	use frame_support::{
+		sp_runtime::{traits::AccountIdConversion, Permill},
+		storage::migration::{put_storage_value, storage_key_iter},
+		traits::{GetStorageVersion, PalletInfoAccess, StorageVersion},
+		PalletId, ReversibleStorageHasher, StorageHasher};
 	use frame_system::pallet_prelude::*;
 
-	use sp_std::vec::Vec;
+	use sp_std::{convert::{TryFrom, TryInto}, vec::Vec};
 	use scale_info::TypeInfo;
 
+	/// Storage version 1 refers to tasks by their nonce based ids.
+	/// Storage version 2 bounds visions, organization names and all member and task lists.
+	/// Storage version 3 indexes the organization of each task, which must be created by its founder.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
+	/// Vision document.
+	pub type VisionOf<T> = BoundedVec<u8, <T as Config>::MaxVisionLength>;
+
+	/// Name of an organization.
+	pub type OrgNameOf<T> = BoundedVec<u8, <T as Config>::MaxOrgNameLength>;
+
 	// Account used in Dao Struct
 	type AccountOf<T> = <T as frame_system::Config>::AccountId;
 
@@ -110,10 +139,44 @@ pub mod pallet {
 	pub trait Config: frame_system::Config + pallet_task::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The maximum length of a vision document.
+		#[pallet::constant]
+		type MaxVisionLength: Get<u32>;
+
+		/// The maximum length of an organization name.
+		#[pallet::constant]
+		type MaxOrgNameLength: Get<u32>;
+
+		/// The maximum amount of members of an organization.
+		#[pallet::constant]
+		type MaxMembers: Get<u32>;
+
+		/// The maximum amount of tasks of an organization.
+		#[pallet::constant]
+		type MaxOrganizationTasks: Get<u32>;
+
+		/// The maximum amount of organizations an account can be a member of.
+		#[pallet::constant]
+		type MaxMemberships: Get<u32>;
+
+		/// The maximum amount of accounts that can sign a vision.
+		#[pallet::constant]
+		type MaxApplicants: Get<u32>;
+
+		/// The DAO pallet's id, used to derive the treasury account of each organization.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The maximum commission an organization can take on its tasks.
+		#[pallet::constant]
+		type MaxCommission: Get<Permill>;
 	}
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -124,12 +187,12 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn vision)]
 	/// Store Vision document in StorageMap as Vector with value: AccountID, BlockNumber
-	pub(super) type Vision<T: Config> = StorageMap<_, Blake2_128Concat, Vec<u8>, (T::AccountId, T::BlockNumber), ValueQuery>;
+	pub(super) type Vision<T: Config> = StorageMap<_, Blake2_128Concat, VisionOf<T>, (T::AccountId, T::BlockNumber), ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn organization)]
 	/// Create organization storage map with key: name and value: Vec<AccountID>
-	pub(super) type Organization<T: Config> = StorageMap<_, Twox64Concat, Vec<u8>, Vec<T::AccountId>, ValueQuery>;
+	pub(super) type Organization<T: Config> = StorageMap<_, Twox64Concat, OrgNameOf<T>, BoundedVec<T::AccountId, T::MaxMembers>, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn organization_count)]
@@ -139,18 +202,28 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn organization_tasks)]
 	/// Create organization storage map with key: name and value: Vec<Hash of task>
-	pub(super) type OrganizationTasks<T: Config> = StorageMap<_, Twox64Concat, Vec<u8>, Vec<T::Hash>, ValueQuery>;
+	pub(super) type OrganizationTasks<T: Config> = StorageMap<_, Twox64Concat, OrgNameOf<T>, BoundedVec<T::Hash, T::MaxOrganizationTasks>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn task_organization)]
+	/// Organization a task belongs to, a task belongs to at most one [key: Hash of task, value: name]
+	pub(super) type TaskOrganization<T: Config> = StorageMap<_, Twox64Concat, T::Hash, OrgNameOf<T>>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn member_of)]
 	/// Storage item that indicates which DAO's a user belongs to [AccountID, Vec]
-	pub(super) type MemberOf<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Vec<u8>, ValueQuery>;
+	pub(super) type MemberOf<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<u8, T::MaxMemberships>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn organization_commission)]
+	/// Commission an organization takes from payouts of its tasks [key: name, value: Permill]
+	pub(super) type OrganizationCommission<T: Config> = StorageMap<_, Twox64Concat, OrgNameOf<T>, Permill>;
 
 
 	#[pallet::storage]
 	#[pallet::getter(fn applicants_to_organization)]
 	/// Storage Map to indicate which user agree with a proposed Vision [Vision, Vec[Account]]
-	pub(super) type ApplicantsToOrganization<T: Config> = StorageMap<_, Twox64Concat, Vec<u8>, Vec<T::AccountId>, ValueQuery>;
+	pub(super) type ApplicantsToOrganization<T: Config> = StorageMap<_, Twox64Concat, VisionOf<T>, BoundedVec<T::AccountId, T::MaxApplicants>, ValueQuery>;
 
 
 	#[pallet::event]
@@ -185,6 +258,9 @@ pub mod pallet {
 
 		/// Task removed from an organization [AccountID, Task Hash]
 		TaskRemoved(T::AccountId, T::Hash),
+
+		/// Commission of an organization set or cleared [AccountID, DAO Name, commission]
+		CommissionSet(T::AccountId, Vec<u8>, Option<Permill>),
 	}
 
 	// Errors inform users that something went wrong.
@@ -220,6 +296,34 @@ pub mod pallet {
 		TaskNotExist,
 		/// Task has been already added to organization.
 		TaskAlreadyExists,
+		/// The vision document is too long.
+		VisionTooLong,
+		/// The organization name is too long.
+		OrgNameTooLong,
+		/// Reached maximum number of members in the organization.
+		ExceedMaxMembers,
+		/// Reached maximum number of tasks in the organization.
+		ExceedMaxOrganizationTasks,
+		/// Reached maximum number of organizations the account can be a member of.
+		ExceedMaxMemberships,
+		/// Reached maximum number of signatures of the vision.
+		ExceedMaxApplicants,
+		/// Only tasks created by the founder can be added to an organization.
+		NotTaskInitiator,
+		/// The commission is above the maximum commission.
+		CommissionTooHigh,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+
+		// Refer to tasks by the ids they got in the task pallet migration, bound storage and index
+		// the organization of each task.
+		fn on_runtime_upgrade() -> Weight {
+			Self::migrate_organization_tasks()
+				.saturating_add(Self::migrate_bounded_storage())
+				.saturating_add(Self::migrate_task_organizations())
+		}
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -234,15 +338,16 @@ pub mod pallet {
 			
 			// Check that the extrinsic was signed and get the signer.
 			let sender = ensure_signed(origin)?;
+			let vision = Self::bounded_vision(&vision_document)?;
 
 			// Verify that the specified vision has not already been created.
-			ensure!(!Vision::<T>::contains_key(&vision_document), Error::<T>::VisionAlreadyExists);
+			ensure!(!Vision::<T>::contains_key(&vision), Error::<T>::VisionAlreadyExists);
 
 			// Get the block number from the FRAME System pallet.
 			let current_block = <frame_system::Pallet<T>>::block_number();
 
 			// Store the vision with the sender and block number.
-			Vision::<T>::insert(&vision_document, (&sender, current_block));
+			Vision::<T>::insert(&vision, (&sender, current_block));
 
 			//Increase Vision Count storage
 			let new_count = Self::vision_count().checked_add(1).ok_or(<Error<T>>::VisionCountOverflow)?;
@@ -260,18 +365,19 @@ pub mod pallet {
             
 			// Check that the extrinsic was signed and get the signer.
             let sender = ensure_signed(origin)?;
+			let vision = Self::bounded_vision(&vision_document)?;
 
             // Verify that the specified vision has been created.
-            ensure!(Vision::<T>::contains_key(&vision_document), Error::<T>::NoSuchVision);
+            ensure!(Vision::<T>::contains_key(&vision), Error::<T>::NoSuchVision);
 
             // Get owner of the vision.
-            let (owner, _) = Vision::<T>::get(&vision_document);
+            let (owner, _) = Vision::<T>::get(&vision);
 
             // Verify that sender of the current call is the vision creator
             ensure!(sender == owner, Error::<T>::NotVisionOwner);
 
             // Remove vision from storage.
-            Vision::<T>::remove(&vision_document);
+            Vision::<T>::remove(&vision);
 
 			// Reduce vision count
 			let new_count = Self::vision_count().saturating_sub(1);
@@ -291,7 +397,7 @@ pub mod pallet {
 			// Check that the extrinsic was signed and get the signer.
 			let who = ensure_signed(origin)?;
 			
-			Self::member_signs_vision(&who, &vision_document)?;
+			Self::member_signs_vision(&who, &Self::bounded_vision(&vision_document)?)?;
 
 			// Emit an event.
 			Self::deposit_event(Event::VisionSigned(who, vision_document));
@@ -306,7 +412,7 @@ pub mod pallet {
 			// Check that the extrinsic was signed and get the signer.
 			let who = ensure_signed(origin)?;
 			
-			Self::member_unsigns_vision(&who, &vision_document)?;
+			Self::member_unsigns_vision(&who, &Self::bounded_vision(&vision_document)?)?;
 
 			// Emit an event.
 			Self::deposit_event(Event::VisionUnsigned(who, vision_document));
@@ -324,7 +430,7 @@ pub mod pallet {
 			//TODO: Ensure only visionary can crate DAOs
 
 			// call public function to create org
-			Self::new_org(&who, &org_name)?;
+			Self::new_org(&who, &Self::bounded_org_name(&org_name)?)?;
 
 			// Emit an event.
 			Self::deposit_event(Event::OrganizationCreated(who, org_name));
@@ -340,7 +446,7 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			// call function to add member to organization
-			Self::add_member_to_organization(&who, &org_name, &account)?;
+			Self::add_member_to_organization(&who, &Self::bounded_org_name(&org_name)?, &account)?;
 
 			// Emit an event.
 			Self::deposit_event(Event::MemberAdded(who, account));
@@ -348,15 +454,15 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Function for adding tasks to an organization [origin, name_org, task_hash]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		/// Function for adding tasks created by the founder to an organization [origin, name_org, task_hash]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4,2))]
 		pub fn add_tasks(origin: OriginFor<T>, org_name: Vec<u8>, task: T::Hash) -> DispatchResult {
 			
 			// Check that the extrinsic was signed and get the signer.
 			let who = ensure_signed(origin)?;
 
 			// call function to add task to organization
-			Self::add_task_to_organization(&who, &org_name, &task)?;
+			Self::add_task_to_organization(&who, &Self::bounded_org_name(&org_name)?, &task)?;
 
 			// Emit an event.
 			Self::deposit_event(Event::TaskAdded(who, task));
@@ -372,7 +478,7 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			// call function to remove member from organization
-			Self::remove_member_from_organization(&who, &org_name, &account)?;
+			Self::remove_member_from_organization(&who, &Self::bounded_org_name(&org_name)?, &account)?;
 
 			// Emit an event.
 			Self::deposit_event(Event::MemberRemoved(who, account));
@@ -381,14 +487,14 @@ pub mod pallet {
 		}
 
 		/// Function for removing tasks from an organization [origin, name_org, task_hash]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,2))]
 		pub fn remove_tasks(origin: OriginFor<T>, org_name: Vec<u8>, task: T::Hash) -> DispatchResult {
 			
 			// Check that the extrinsic was signed and get the signer.
 			let who = ensure_signed(origin)?;
 
 			// call function to add task to organization
-			Self::remove_task_from_organization(&who, &org_name, &task)?;
+			Self::remove_task_from_organization(&who, &Self::bounded_org_name(&org_name)?, &task)?;
 
 			// Emit an event.
 			Self::deposit_event(Event::TaskRemoved(who, task));
@@ -397,29 +503,46 @@ pub mod pallet {
 		}
 
 		/// Function for dissolving an organization [origin, name_org]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 3 + T::MaxOrganizationTasks::get() as Weight))]
 		pub fn dissolve_organization(origin: OriginFor<T>, org_name: Vec<u8>) -> DispatchResult {
 			
 			// Check that the extrinsic was signed and get the signer.
 			let who = ensure_signed(origin)?;
 
 			// call function to remove organization
-			Self::remove_org(&who, &org_name)?;
+			Self::remove_org(&who, &Self::bounded_org_name(&org_name)?)?;
 
 			// Emit an event.
 			Self::deposit_event(Event::OrganizationDissolved(who, org_name));
 			
 			Ok(())
 		}
+
+		/// Function for the founder to override the commission on the organization's tasks, up to the
+		/// maximum commission [origin, name_org, commission]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,1))]
+		pub fn set_commission(origin: OriginFor<T>, org_name: Vec<u8>, commission: Option<Permill>) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let who = ensure_signed(origin)?;
+
+			// call function to set the commission of the organization
+			Self::change_commission(&who, &Self::bounded_org_name(&org_name)?, commission)?;
+
+			// Emit an event.
+			Self::deposit_event(Event::CommissionSet(who, org_name, commission));
+			
+			Ok(())
+		}
 		
 	}
 
 	// *** Helper functions *** //
 	impl<T:Config> Pallet<T> {
-		pub fn new_org(from_initiator: &T::AccountId, org_name: &[u8]) -> Result<(), Error<T>> {
+		pub fn new_org(from_initiator: &T::AccountId, org_name: &OrgNameOf<T>) -> Result<(), Error<T>> {
 			
 			let mut org = <Pallet<T>>::organization(org_name);
-			org.push(from_initiator.clone());
+			org.try_push(from_initiator.clone()).map_err(|_| <Error<T>>::ExceedMaxMembers)?;
 
 			// Insert vector into Hashmap
 			<Organization<T>>::insert(org_name, org);
@@ -431,13 +554,17 @@ pub mod pallet {
 			Ok(())
 		}
 
-		pub fn remove_org(from_initiator: &T::AccountId, org_name: &[u8]) -> Result<(), Error<T>> {
+		pub fn remove_org(from_initiator: &T::AccountId, org_name: &OrgNameOf<T>) -> Result<(), Error<T>> {
 			
 			// check if its DAO original creator
 			Self::is_dao_founder(from_initiator, org_name)?;
 
-			// Remove organizational instance
+			// Remove organizational instance and release its tasks
 			<Organization<T>>::remove(org_name);
+			<OrganizationCommission<T>>::remove(org_name);
+			for task in <OrganizationTasks<T>>::take(org_name).iter() {
+				<TaskOrganization<T>>::remove(task);
+			}
 
 			// Reduce organization count
 			let new_count = Self::organization_count().saturating_sub(1);
@@ -446,7 +573,37 @@ pub mod pallet {
 			Ok(())
 		}
 
-		pub fn add_member_to_organization(from_initiator: &T::AccountId, org_name: &[u8], account: &T::AccountId ) -> Result<(), Error<T>> {
+		pub fn change_commission(from_initiator: &T::AccountId, org_name: &OrgNameOf<T>, commission: Option<Permill>) -> Result<(), Error<T>> {
+			// Check if organization exists
+			ensure!(<Organization<T>>::contains_key(org_name), <Error<T>>::InvalidOrganization);
+
+			// check if its DAO original creator
+			Self::is_dao_founder(from_initiator, org_name)?;
+
+			ensure!(commission.map_or(true, |rate| rate <= T::MaxCommission::get()), <Error<T>>::CommissionTooHigh);
+			<OrganizationCommission<T>>::set(org_name, commission);
+
+			Ok(())
+		}
+
+		// Organization that owns a task, if any
+		pub fn organization_of(task_id: &T::Hash) -> Option<OrgNameOf<T>> {
+			Self::task_organization(task_id)
+		}
+
+		// Organization of a task created by its founder, only those pay commission to the organization
+		pub fn treasury_organization(task_id: &T::Hash) -> Option<OrgNameOf<T>> {
+			let org_name = Self::organization_of(task_id)?;
+			let initiator = pallet_task::Pallet::<T>::tasks(task_id)?.initiator;
+			(Self::organization(&org_name).first() == Some(&initiator)).then(|| org_name)
+		}
+
+		// Account holding the funds of an organization, such as commissions on its tasks
+		pub fn treasury_account(org_name: &OrgNameOf<T>) -> T::AccountId {
+			<T as Config>::PalletId::get().into_sub_account(org_name)
+		}
+
+		pub fn add_member_to_organization(from_initiator: &T::AccountId, org_name: &OrgNameOf<T>, account: &T::AccountId ) -> Result<(), Error<T>> {
 			// Check if organization exists
 			let mut members = Self::organization(org_name);
 			ensure!(!members.is_empty() , Error::<T>::InvalidOrganization);
@@ -458,18 +615,18 @@ pub mod pallet {
 			ensure!(!members.contains(account), <Error<T>>::AlreadyMember);
 			
 			// Insert account into organization
-			members.push(account.clone());
+			members.try_push(account.clone()).map_err(|_| <Error<T>>::ExceedMaxMembers)?;
 			<Organization<T>>::insert(org_name, &members);
 			
 			// Insert organizations into MemberOf
 			let mut organizations = Self::member_of(&account);
-			organizations.push(org_name[0]);
+			organizations.try_push(org_name[0]).map_err(|_| <Error<T>>::ExceedMaxMemberships)?;
 			<MemberOf<T>>::insert(&account, organizations);
 			
 			Ok(())
 		}
 
-		pub fn add_task_to_organization(from_initiator: &T::AccountId, org_name: &[u8], task: &T::Hash ) -> Result<(), Error<T>> {
+		pub fn add_task_to_organization(from_initiator: &T::AccountId, org_name: &OrgNameOf<T>, task: &T::Hash ) -> Result<(), Error<T>> {
 			// Check if organization exists
 			let members = Self::organization(org_name);
 			ensure!(!members.is_empty() , Error::<T>::InvalidOrganization);
@@ -477,19 +634,23 @@ pub mod pallet {
 			// check if its DAO original creator
 			Self::is_dao_founder(from_initiator, org_name)?;
 
-			// Check if already contains the task
-			let mut tasks = Self::organization_tasks(org_name);
-			ensure!(!tasks.contains(task), <Error<T>>::TaskAlreadyExists);
+			// Only the founder's own tasks can be added, since members arbitrate them
+			let task_info = pallet_task::Pallet::<T>::tasks(task).ok_or(<Error<T>>::TaskNotExist)?;
+			ensure!(task_info.initiator == *from_initiator, <Error<T>>::NotTaskInitiator);
+
+			// Check if the task already belongs to this or another organization
+			ensure!(!<TaskOrganization<T>>::contains_key(task), <Error<T>>::TaskAlreadyExists);
 			
 			// Insert task into organization
-			tasks.push(*task);
+			let mut tasks = Self::organization_tasks(org_name);
+			tasks.try_push(*task).map_err(|_| <Error<T>>::ExceedMaxOrganizationTasks)?;
 			<OrganizationTasks<T>>::insert(org_name, &tasks);
-
+			<TaskOrganization<T>>::insert(task, org_name);
 			
 			Ok(())
 		}
 
-		pub fn remove_member_from_organization(from_initiator: &T::AccountId, org_name: &[u8], account: &T::AccountId ) -> Result<(), Error<T>> {
+		pub fn remove_member_from_organization(from_initiator: &T::AccountId, org_name: &OrgNameOf<T>, account: &T::AccountId ) -> Result<(), Error<T>> {
 			// Check if organization exists
 			let org = <Pallet<T>>::organization(org_name);
 			ensure!(!org.is_empty() , Error::<T>::InvalidOrganization);
@@ -514,7 +675,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		pub fn remove_task_from_organization(from_initiator: &T::AccountId, org_name: &[u8], task: &T::Hash ) -> Result<(), Error<T>> {
+		pub fn remove_task_from_organization(from_initiator: &T::AccountId, org_name: &OrgNameOf<T>, task: &T::Hash ) -> Result<(), Error<T>> {
 			// Check if organization exists
 			let org = <Pallet<T>>::organization(org_name);
 			ensure!(!org.is_empty() , Error::<T>::InvalidOrganization);
@@ -524,16 +685,17 @@ pub mod pallet {
 
 			// Find task and remove from Vector
 			let mut tasks = <Pallet<T>>::organization_tasks(org_name);
-			let index = tasks.binary_search(task).ok().ok_or(<Error<T>>::TaskNotExist)?;
+			let index = tasks.iter().position(|id| id == task).ok_or(<Error<T>>::TaskNotExist)?;
 			tasks.remove(index);
 			
 			// Update organization tasks
 			<OrganizationTasks<T>>::insert(org_name, tasks);
+			<TaskOrganization<T>>::remove(task);
 			
 			Ok(())
 		}
 
-		pub fn member_signs_vision(from_initiator: &T::AccountId, vision_document: &[u8]) -> Result<(), Error<T>> {
+		pub fn member_signs_vision(from_initiator: &T::AccountId, vision_document: &VisionOf<T>) -> Result<(), Error<T>> {
 
 			// Verify that the specified vision has been created.
             ensure!(Vision::<T>::contains_key(vision_document), Error::<T>::NoSuchVision);
@@ -545,7 +707,7 @@ pub mod pallet {
 
 			// Ensure not signed already
 			ensure!(!members.contains(from_initiator), <Error<T>>::AlreadySigned);
-			members.push(from_initiator.clone());
+			members.try_push(from_initiator.clone()).map_err(|_| <Error<T>>::ExceedMaxApplicants)?;
 			
 			// Update storage.
 			<ApplicantsToOrganization<T>>::insert(vision_document, members);
@@ -553,7 +715,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		pub fn member_unsigns_vision(from_initiator: &T::AccountId, vision_document: &[u8]) -> Result<(), Error<T>> {
+		pub fn member_unsigns_vision(from_initiator: &T::AccountId, vision_document: &VisionOf<T>) -> Result<(), Error<T>> {
 
 			// Verify that the specified vision has been created.
             ensure!(Vision::<T>::contains_key(vision_document), Error::<T>::NoSuchVision);
@@ -575,11 +737,192 @@ pub mod pallet {
 
 
 
-		pub fn is_dao_founder(from_initiator: &T::AccountId, org_name: &[u8]) -> Result<bool, Error<T>> {
+		pub fn bounded_vision(vision_document: &[u8]) -> Result<VisionOf<T>, Error<T>> {
+			VisionOf::<T>::try_from(vision_document.to_vec()).map_err(|_| <Error<T>>::VisionTooLong)
+		}
+
+		pub fn bounded_org_name(org_name: &[u8]) -> Result<OrgNameOf<T>, Error<T>> {
+			OrgNameOf::<T>::try_from(org_name.to_vec()).map_err(|_| <Error<T>>::OrgNameTooLong)
+		}
+
+		pub fn is_dao_founder(from_initiator: &T::AccountId, org_name: &OrgNameOf<T>) -> Result<bool, Error<T>> {
 			let first_account = Self::organization(org_name);
 			if first_account[0] == *from_initiator {
 				Ok(true)
 			} else { Err(Error::<T>::NotOrganizationCreator) }
 		}
 	}
+
+	impl<T: Config> Pallet<T> {
+		// Replaces legacy task ids in `OrganizationTasks` with the ids assigned by pallet-task.
+		// The task pallet is migrated first, whichever order the runtime upgrades pallets in.
+		pub fn migrate_organization_tasks() -> Weight {
+			if Self::on_chain_storage_version() >= 1 {
+				return 0;
+			}
+
+			let task_weight = pallet_task::Pallet::<T>::migrate_task_ids();
+			let mut reads: Weight = 1;
+			let mut writes: Weight = 1;
+
+			// Storage is read with the layout of version 0
+			let organizations: Vec<(Vec<u8>, Vec<T::Hash>)> = storage_key_iter::<_, _, Twox64Concat>(Self::name().as_bytes(), b"OrganizationTasks").collect();
+			for (org_name, tasks) in organizations {
+				reads += 1 + tasks.len() as Weight;
+				writes += 1;
+				let mut migrated: Vec<T::Hash> = Vec::new();
+				for task in tasks {
+					let task = pallet_task::Pallet::<T>::legacy_task_ids(task).unwrap_or(task);
+					if !migrated.contains(&task) {
+						migrated.push(task);
+					}
+				}
+				put_storage_value(Self::name().as_bytes(), b"OrganizationTasks", &Twox64Concat::hash(&org_name.encode()), migrated);
+			}
+
+			StorageVersion::new(1).put::<Self>();
+
+			task_weight.saturating_add(T::DbWeight::get().reads_writes(reads, writes))
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		// Re-inserts all maps written before storage version 2 with bounded types. Entries keyed by
+		// oversize visions or organization names are removed, oversize lists are truncated.
+		pub fn migrate_bounded_storage() -> Weight {
+			if Self::on_chain_storage_version() >= 2 {
+				return 0;
+			}
+
+			let mut reads: Weight = 2;
+			let mut writes: Weight = 3;
+
+			let visions = Self::drain_legacy::<Vec<u8>, (T::AccountId, T::BlockNumber), Blake2_128Concat>(b"Vision");
+			for (vision, owner) in visions {
+				reads += 1;
+				writes += 1;
+				match VisionOf::<T>::try_from(vision) {
+					Ok(vision) => <Vision<T>>::insert(vision, owner),
+					Err(_) => <VisionCount<T>>::mutate(|count| *count = count.saturating_sub(1)),
+				}
+			}
+
+			let applicants = Self::drain_legacy::<Vec<u8>, Vec<T::AccountId>, Twox64Concat>(b"ApplicantsToOrganization");
+			for (vision, accounts) in applicants {
+				reads += 1;
+				writes += 1;
+				if let Ok(vision) = VisionOf::<T>::try_from(vision) {
+					<ApplicantsToOrganization<T>>::insert(vision, Self::truncated(accounts));
+				}
+			}
+
+			let organizations = Self::drain_legacy::<Vec<u8>, Vec<T::AccountId>, Twox64Concat>(b"Organization");
+			for (org_name, members) in organizations {
+				reads += 1;
+				writes += 1;
+				match OrgNameOf::<T>::try_from(org_name) {
+					Ok(org_name) => <Organization<T>>::insert(org_name, Self::truncated(members)),
+					Err(_) => <OrganizationCount<T>>::mutate(|count| *count = count.saturating_sub(1)),
+				}
+			}
+
+			let organization_tasks = Self::drain_legacy::<Vec<u8>, Vec<T::Hash>, Twox64Concat>(b"OrganizationTasks");
+			for (org_name, tasks) in organization_tasks {
+				reads += 1;
+				writes += 1;
+				if let Ok(org_name) = OrgNameOf::<T>::try_from(org_name) {
+					<OrganizationTasks<T>>::insert(org_name, Self::truncated(tasks));
+				}
+			}
+
+			let memberships = Self::drain_legacy::<T::AccountId, Vec<u8>, Twox64Concat>(b"MemberOf");
+			for (account, organizations) in memberships {
+				reads += 1;
+				writes += 1;
+				<MemberOf<T>>::insert(account, Self::truncated(organizations));
+			}
+
+			StorageVersion::new(2).put::<Self>();
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		// Indexes the organization of each task. Tasks that were not created by the founder of their
+		// organization, or that already belong to another organization, are removed from it.
+		pub fn migrate_task_organizations() -> Weight {
+			if Self::on_chain_storage_version() >= 3 {
+				return 0;
+			}
+
+			let mut reads: Weight = 1;
+			let mut writes: Weight = 1;
+
+			let organizations: Vec<(OrgNameOf<T>, BoundedVec<T::Hash, T::MaxOrganizationTasks>)> = <OrganizationTasks<T>>::iter().collect();
+			for (org_name, mut tasks) in organizations {
+				reads += 2 + 2 * tasks.len() as Weight;
+				writes += 1 + tasks.len() as Weight;
+				let founder = Self::organization(&org_name).first().cloned();
+				tasks.retain(|task| {
+					let owned = pallet_task::Pallet::<T>::tasks(task)
+						.map_or(false, |task_info| Some(task_info.initiator) == founder);
+					if !owned || <TaskOrganization<T>>::contains_key(task) {
+						log::warn!("removing task {:?} from organization {:?}", task, org_name);
+						return false;
+					}
+					<TaskOrganization<T>>::insert(task, &org_name);
+					true
+				});
+				<OrganizationTasks<T>>::insert(&org_name, tasks);
+			}
+
+			STORAGE_VERSION.put::<Self>();
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		// Removes and returns all entries of a map of this pallet, decoded with a legacy layout
+		fn drain_legacy<K: Decode, V: Decode, H: ReversibleStorageHasher>(item: &[u8]) -> Vec<(K, V)> {
+			storage_key_iter::<K, V, H>(Self::name().as_bytes(), item).drain().collect()
+		}
+
+		// Keeps the first items that fit in the bound
+		fn truncated<V, S: Get<u32>>(mut items: Vec<V>) -> BoundedVec<V, S> {
+			items.truncate(S::get() as usize);
+			items.try_into().unwrap_or_default()
+		}
+	}
+
+	// Members of the organization that owns a task arbitrate its disputes
+	impl<T: Config> pallet_task::TaskArbitrators<T::AccountId, T::Hash> for Pallet<T> {
+		fn arbitrators(task_id: &T::Hash) -> Vec<T::AccountId> {
+			Self::organization_of(task_id)
+				.map(|org_name| Self::organization(org_name).to_vec())
+				.unwrap_or_default()
+		}
+	}
+
+	// Commissions on tasks the founder added to an organization go to its treasury, at its own rate if set
+	impl<T: Config> pallet_task::TaskTreasury<T::AccountId, T::Hash> for Pallet<T> {
+		fn treasury(task_id: &T::Hash) -> Option<T::AccountId> {
+			Self::treasury_organization(task_id).map(|org_name| Self::treasury_account(&org_name))
+		}
+
+		fn commission(task_id: &T::Hash) -> Option<Permill> {
+			Self::treasury_organization(task_id).and_then(Self::organization_commission)
+		}
+	}
+
+	// Tasks of an organization are identified by the account of the organization, whoever created them
+	impl<T: Config> pallet_task::TaskOrganizations<T::AccountId, T::Hash> for Pallet<T> {
+		fn organization(task_id: &T::Hash) -> Option<T::AccountId> {
+			Self::organization_of(task_id).map(|org_name| Self::treasury_account(&org_name))
+		}
+
+		// Closed, expired and removed tasks are dropped from both indexes of their organization
+		fn task_removed(task_id: &T::Hash) {
+			if let Some(org_name) = <TaskOrganization<T>>::take(task_id) {
+				<OrganizationTasks<T>>::mutate(&org_name, |tasks| tasks.retain(|id| id != task_id));
+			}
+		}
+	}
 }
\ No newline at end of file
@@ -1,10 +1,11 @@
 use crate as pallet_dao;
-use frame_support::parameter_types;
-use frame_system as system;
+use frame_support::{PalletId, parameter_types, traits::SortedMembers};
+use frame_system::{self as system, EnsureRoot, EnsureSignedBy};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	Permill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -19,6 +20,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
 		Dao: pallet_dao::{Pallet, Call, Storage, Event<T>},
 		Task: pallet_task::{Pallet, Call, Storage, Event<T>},
 		Profile: pallet_profile::{Pallet, Call, Storage, Event<T>},
@@ -56,34 +58,184 @@ impl system::Config for Test {
 	type OnSetCode = ();
 }
 
+parameter_types! {
+	// Visions are at most 32 bytes and organization names at most 8 bytes
+	pub const MaxVisionLength: u32 = 32;
+	pub const MaxOrgNameLength: u32 = 8;
+	// Organizations have at most 5 members and 5 tasks
+	pub const MaxMembers: u32 = 5;
+	pub const MaxOrganizationTasks: u32 = 5;
+	// Accounts can be a member of at most 3 organizations
+	pub const MaxMemberships: u32 = 3;
+	// Visions can be signed by at most 5 accounts
+	pub const MaxApplicants: u32 = 5;
+	// Organization treasuries are derived from the DAO pallet account
+	pub const DaoPalletId: PalletId = PalletId(*b"py/daoor");
+	// Organizations take at most 50% commission on their tasks
+	pub const MaxCommission: Permill = Permill::from_percent(50);
+}
+
 impl pallet_dao::Config for Test {
 	type Event = Event;
+	type MaxVisionLength = MaxVisionLength;
+	type MaxOrgNameLength = MaxOrgNameLength;
+	type MaxMembers = MaxMembers;
+	type MaxOrganizationTasks = MaxOrganizationTasks;
+	type MaxMemberships = MaxMemberships;
+	type MaxApplicants = MaxApplicants;
+	type PalletId = DaoPalletId;
+	type MaxCommission = MaxCommission;
 }
 
 parameter_types! {
+	// Titles are at most 8 bytes and specifications at most 64 bytes
+	pub const MaxTitleLength: u32 = 8;
+	pub const MaxSpecificationLength: u32 = 64;
 	// One can owned at most 77 tasks
 	pub const MaxTasksOwned: u32 = 77;
+	// At most 7 tasks can expire in the same block
+	pub const MaxDeadlinesPerBlock: u32 = 7;
+	pub const MinTaskBudget: u64 = 1;
+	// A task can have at most 3 applicants
+	pub const MaxApplicantsPerTask: u32 = 3;
+	// A task can have at most 3 milestones
+	pub const MaxMilestones: u32 = 3;
+	// A deliverable content identifier is at most 64 bytes
+	pub const MaxDeliverableLength: u32 = 64;
+	// At most 3 deliverable revisions are kept per task
+	pub const MaxRevisions: u32 = 3;
+	// At most 3 versions are kept per task
+	pub const MaxTaskVersions: u32 = 3;
+	// Tasks have at most 3 prerequisites, searched 4 levels deep
+	pub const MaxPrerequisites: u32 = 3;
+	pub const MaxDependencyDepth: u32 = 4;
+	// At most 3 tasks can depend on a task, and a task has at most 3 subtasks
+	pub const MaxDependents: u32 = 3;
+	pub const MaxSubtasks: u32 = 3;
+	// Asset budgets are escrowed by the task pallet account
+	pub const TaskPalletId: PalletId = PalletId(*b"py/tasks");
+	// Teams have at most 3 volunteers
+	pub const MaxTeamSize: u32 = 3;
+	// At most 3 accounts can fund a task
+	pub const MaxContributors: u32 = 3;
+	// Volunteers can be unassigned after 5 blocks without activity
+	pub const UnassignGracePeriod: u64 = 5;
+	// A skill tag is at most 8 bytes
+	pub const MaxTagLength: u32 = 8;
+	// A task can have at most 3 skill tags
+	pub const MaxTagsPerTask: u32 = 3;
+	// Raising a dispute reserves 5
+	pub const DisputeDeposit: u64 = 5;
+	// Arbitrators have 10 blocks to reach a ruling
+	pub const DisputeTimeout: u64 = 10;
+	// A dispute is resolved after 2 votes
+	pub const DisputeQuorum: u32 = 2;
+	// At most 3 arbitrators can vote on a dispute
+	pub const MaxArbitrators: u32 = 3;
+	// Closed tasks can be rated for 10 blocks
+	pub const RatingPeriod: u64 = 10;
+	// Payouts pay 10% commission unless the organization sets its own
+	pub const Commission: Permill = Permill::from_percent(10);
+}
+
+// Accounts that can arbitrate any dispute
+pub struct Arbiters;
+impl SortedMembers<u64> for Arbiters {
+	fn sorted_members() -> Vec<u64> {
+		Vec::new()
+	}
 }
 
 impl pallet_task::Config for Test {
 	type Event = Event;
 	type Currency = Balances;
+	type AssetId = u32;
+	type Assets = Assets;
+	type PalletId = TaskPalletId;
+	type MaxTitleLength = MaxTitleLength;
+	type MaxSpecificationLength = MaxSpecificationLength;
 	type MaxTasksOwned = MaxTasksOwned;
+	type MaxDeadlinesPerBlock = MaxDeadlinesPerBlock;
+	type MinTaskBudget = MinTaskBudget;
+	type MaxApplicantsPerTask = MaxApplicantsPerTask;
+	type MaxMilestones = MaxMilestones;
+	type MaxDeliverableLength = MaxDeliverableLength;
+	type MaxRevisions = MaxRevisions;
+	type MaxTaskVersions = MaxTaskVersions;
+	type MaxPrerequisites = MaxPrerequisites;
+	type MaxDependencyDepth = MaxDependencyDepth;
+	type MaxDependents = MaxDependents;
+	type MaxSubtasks = MaxSubtasks;
+	type MaxTeamSize = MaxTeamSize;
+	type MaxContributors = MaxContributors;
+	type UnassignGracePeriod = UnassignGracePeriod;
+	type TagOrigin = EnsureRoot<u64>;
+	type MaxTagLength = MaxTagLength;
+	type MaxTagsPerTask = MaxTagsPerTask;
+	type ArbitratorOrigin = EnsureSignedBy<Arbiters, u64>;
+	type Arbitrators = Dao;
+	type DisputeDeposit = DisputeDeposit;
+	type DisputeTimeout = DisputeTimeout;
+	type DisputeQuorum = DisputeQuorum;
+	type MaxArbitrators = MaxArbitrators;
+	type RatingPeriod = RatingPeriod;
+	type Commission = Commission;
+	type Treasury = Dao;
+	type Organizations = Dao;
+}
+
+parameter_types! {
+	// Usernames are at most 16 bytes and interests at most 64 bytes
+	pub const MaxUsernameLength: u32 = 16;
+	pub const MaxInterestsLength: u32 = 64;
+	// Reputation decays slowly enough not to affect the other tests
+	pub const ReputationHalfLife: u64 = 1_000;
+	// Abandoning a task costs 1 reputation, a dispute 2 and a deadline 3
+	pub const AbandonPenalty: u32 = 1;
+	pub const DisputePenalty: u32 = 2;
+	pub const DeadlinePenalty: u32 = 3;
+	// Reputation never goes below 0 and every profile can take tasks
+	pub const ReputationFloor: u32 = 0;
+	pub const MinReputation: u32 = 0;
+	// Skills are at most 8 bytes and a profile has at most 3
+	pub const MaxSkillLength: u32 = 8;
+	pub const MaxSkills: u32 = 3;
+	// Endorsing stakes 2 reputation or reserves 5, a skill has at most 3 endorsements
+	pub const EndorsementReputation: u32 = 2;
+	pub const EndorsementDeposit: u64 = 5;
+	pub const MaxEndorsements: u32 = 3;
 }
 
 impl pallet_profile::Config for Test {
 	type Event = Event;
 	type Currency =  Balances;
+	type MaxUsernameLength = MaxUsernameLength;
+	type MaxInterestsLength = MaxInterestsLength;
+	type ReputationHandler = ();
+	type ReputationHalfLife = ReputationHalfLife;
+	type AbandonPenalty = AbandonPenalty;
+	type DisputePenalty = DisputePenalty;
+	type DeadlinePenalty = DeadlinePenalty;
+	type ReputationFloor = ReputationFloor;
+	type MinReputation = MinReputation;
+	type SkillRegistry = Task;
+	type MaxSkillLength = MaxSkillLength;
+	type MaxSkills = MaxSkills;
+	type EndorsementReputation = EndorsementReputation;
+	type EndorsementDeposit = EndorsementDeposit;
+	type MaxEndorsements = MaxEndorsements;
 }
 
 parameter_types! {
 	pub const ExistentialDeposit: u64 = 1;
+	// Task budgets are reserved under their task id, an account can fund at most 100 tasks at once
+	pub const MaxReserves: u32 = 100;
 }
 
 impl pallet_balances::Config for Test {
-	type ReserveIdentifier = [u8; 8];
+	type ReserveIdentifier = H256;
 	type MaxLocks = ();
-	type MaxReserves = ();
+	type MaxReserves = MaxReserves;
 	type Balance = u64;
 	type Event = Event;
 	type DustRemoval = ();
@@ -92,6 +244,30 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const AssetDeposit: u64 = 1;
+	pub const ApprovalDeposit: u64 = 1;
+	pub const StringLimit: u32 = 50;
+	pub const MetadataDepositBase: u64 = 1;
+	pub const MetadataDepositPerByte: u64 = 1;
+}
+
+impl pallet_assets::Config for Test {
+	type Event = Event;
+	type Balance = u64;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<u64>;
+	type AssetDeposit = AssetDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = StringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type WeightInfo = ();
+}
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
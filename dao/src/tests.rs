@@ -1,5 +1,31 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{mock::*, Error, OrgNameOf, VisionOf};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::migration::put_storage_value,
+	traits::{Currency, GetStorageVersion, PalletInfoAccess, StorageVersion},
+	Blake2_128Concat, BoundedVec, StorageHasher, Twox64Concat};
+use pallet_task::TaskArbitrators;
+use sp_runtime::Permill;
+use sp_std::convert::TryFrom;
+
+// Storage key of an organization name.
+fn org_key(name: &[u8]) -> OrgNameOf<Test> {
+	Dao::bounded_org_name(name).unwrap()
+}
+
+// Storage key of a vision document.
+fn vision_key(document: &[u8]) -> VisionOf<Test> {
+	Dao::bounded_vision(document).unwrap()
+}
+
+// Creates a profile and a task for an account and returns the id of the task.
+fn create_task(owner: u64) -> sp_core::H256 {
+	Balances::make_free_balance_be(&owner, 1000);
+	assert_ok!(Profile::create_profile(Origin::signed(owner), b"Owner".to_vec(), Vec::new()));
+	assert_ok!(Task::create_task(Origin::signed(owner), b"Task".to_vec(), Vec::new(), 100, 50, Vec::new()));
+	*Task::tasks_owned(owner).last().unwrap()
+}
 
 
 
@@ -57,7 +83,7 @@ fn can_remove_vision() {
 		assert_ok!(Dao::remove_vision(Origin::signed(1), VISION.to_vec()));
 
 		// TODO: Enforce stronger check on Vision test
-		assert_eq!(Dao::vision(VISION.to_vec()).0, 0);
+		assert_eq!(Dao::vision(vision_key(VISION)).0, 0);
 	});
 }
 
@@ -122,7 +148,7 @@ fn user_can_sign_onto_vision() {
 		assert_ok!(Dao::sign_vision(Origin::signed(1), VISION.to_vec()));
 
 		// Ensure the length of VisionSigner has increased
-		assert_eq!(Dao::applicants_to_organization(VISION.to_vec()).len(), 1);
+		assert_eq!(Dao::applicants_to_organization(vision_key(VISION)).len(), 1);
 	});
 }
 
@@ -140,13 +166,13 @@ fn user_can_unsign_from_vision() {
 		assert_ok!(Dao::sign_vision(Origin::signed(2), VISION.to_vec()));
 
 		// Ensure the length of VisionSigners has increased
-		assert_eq!(Dao::applicants_to_organization(VISION.to_vec()).len(), 1);
+		assert_eq!(Dao::applicants_to_organization(vision_key(VISION)).len(), 1);
 
 		// Ensure a user can unsign onto vision. 
 		assert_ok!(Dao::unsign_vision(Origin::signed(2), VISION.to_vec()));
 
 		// Ensure the length of VisionSigners has increased
-		assert_eq!(Dao::applicants_to_organization(VISION.to_vec()).len(), 0);
+		assert_eq!(Dao::applicants_to_organization(vision_key(VISION)).len(), 0);
 	});
 }
 
@@ -227,11 +253,11 @@ fn can_create_an_organization() {
 		// Ensure organization can be created
 		assert_ok!(Dao::create_organization(Origin::signed(7), ORG_NAME.to_vec()));
 
-		let org = Dao::organization(ORG_NAME);
+		let org = Dao::organization(org_key(ORG_NAME));
 
 		// Ensure the length of organization is equal to 1
-		assert_eq!(Dao::organization(ORG_NAME.to_vec()).len(), 1);
-		assert_eq!(org, &[7]);
+		assert_eq!(Dao::organization(org_key(ORG_NAME)).len(), 1);
+		assert_eq!(org.to_vec(), &[7]);
 	});
 }
 
@@ -264,8 +290,8 @@ fn can_create_multiple_organization() {
 		assert_ok!(Dao::create_organization(Origin::signed(2), ORG_NAME2.to_vec()));
 
 		// Ensure each organization was created successfully
-		assert_eq!(Dao::organization(ORG_NAME1.to_vec()).len(), 1);
-		assert_eq!(Dao::organization(ORG_NAME2.to_vec()).len(), 1);
+		assert_eq!(Dao::organization(org_key(ORG_NAME1)).len(), 1);
+		assert_eq!(Dao::organization(org_key(ORG_NAME2)).len(), 1);
 		
 		// Ensure organization count is 2
 		assert_eq!(Dao::organization_count(), 2);
@@ -284,13 +310,13 @@ fn can_remove_an_organization() {
 		assert_ok!(Dao::create_organization(Origin::signed(1), ORG_NAME.to_vec()));
 
 		// Ensure the length of organization is equal to 1
-		assert_eq!(Dao::organization(ORG_NAME.to_vec()).len(), 1);
+		assert_eq!(Dao::organization(org_key(ORG_NAME)).len(), 1);
 
 		// Ensure organization can be removed
 		assert_ok!(Dao::dissolve_organization(Origin::signed(1), ORG_NAME.to_vec()));
 
 		// Ensure the organization has been removed by checking the length
-		assert_eq!(Dao::organization(ORG_NAME.to_vec()).len(), 0);
+		assert_eq!(Dao::organization(org_key(ORG_NAME)).len(), 0);
 	});
 }
 
@@ -305,14 +331,14 @@ fn removing_organization_decreases_organization_count() {
 		assert_ok!(Dao::create_organization(Origin::signed(1), ORG_NAME.to_vec()));
 
 		// Ensure the length of organization is equal to 1, and count is 1
-		assert_eq!(Dao::organization(ORG_NAME.to_vec()).len(), 1);
+		assert_eq!(Dao::organization(org_key(ORG_NAME)).len(), 1);
 		assert_eq!(Dao::organization_count(), 1);
 
 		// Ensure organization can be removed
 		assert_ok!(Dao::dissolve_organization(Origin::signed(1), ORG_NAME.to_vec()));
 
 		// Ensure the organization has been removed by checking the length
-		assert_eq!(Dao::organization(ORG_NAME.to_vec()).len(), 0);
+		assert_eq!(Dao::organization(org_key(ORG_NAME)).len(), 0);
 
 		// Ensure organization count is 0
 		assert_eq!(Dao::organization_count(), 0);
@@ -333,7 +359,7 @@ fn only_creator_can_remove_their_organization() {
 		assert_noop!(Dao::dissolve_organization(Origin::signed(2), ORG_NAME.to_vec()), Error::<Test>::NotOrganizationCreator);
 
 		// Ensure the organization has not been deleted
-		assert_eq!(Dao::organization(ORG_NAME.to_vec()).len(), 1);
+		assert_eq!(Dao::organization(org_key(ORG_NAME)).len(), 1);
 
 	});
 }
@@ -352,7 +378,7 @@ fn can_add_user_to_organization() {
 		assert_ok!(Dao::add_members(Origin::signed(1), ORG_NAME.to_vec(), 4));
 
 		// Ensure the organization has 2 members (creator abd user4)
-		assert_eq!(Dao::organization(ORG_NAME.to_vec()).len(), 2);
+		assert_eq!(Dao::organization(org_key(ORG_NAME)).len(), 2);
 
 	});
 }
@@ -461,7 +487,7 @@ fn can_remove_users_from_organization() {
 		assert_ok!(Dao::remove_members(Origin::signed(1), ORG_NAME.to_vec(), 4));
 
 		// Validate Ensure length of users in org is 2
-		assert_eq!(Dao::organization(ORG_NAME.to_vec()).len(), 2);
+		assert_eq!(Dao::organization(org_key(ORG_NAME)).len(), 2);
 
 	});
 }
@@ -480,7 +506,7 @@ fn can_only_remove_users_that_belong_to_organization() {
 		assert_ok!(Dao::add_members(Origin::signed(1), ORG_NAME.to_vec(), 4));
 
 		// Ensure length of users in org is 2
-		assert_eq!(Dao::organization(ORG_NAME.to_vec()).len(), 2);
+		assert_eq!(Dao::organization(org_key(ORG_NAME)).len(), 2);
 
 		// Ensure error is thrown if user is not in organization
 		assert_noop!(Dao::remove_members(Origin::signed(1), ORG_NAME.to_vec(), 5), Error::<Test>::NotMember);
@@ -581,7 +607,7 @@ fn can_add_tasks_to_organization() {
 
 		// Create Static Organization name
 		const ORG_NAME: &'static [u8] = &[7];
-		let hash = sp_core::H256::zero();
+		let hash = create_task(1);
 
 		// Ensure organization can be created
 		assert_ok!(Dao::create_organization(Origin::signed(1), ORG_NAME.to_vec()));
@@ -590,7 +616,7 @@ fn can_add_tasks_to_organization() {
 		assert_ok!(Dao::add_tasks(Origin::signed(1), ORG_NAME.to_vec(), hash));
 
 		// Ensure the organization has 1 task
-		assert_eq!(Dao::organization_tasks(ORG_NAME.to_vec()).len(), 1);
+		assert_eq!(Dao::organization_tasks(org_key(ORG_NAME)).len(), 1);
 
 	});
 }
@@ -601,7 +627,7 @@ fn can_add_task_to_organization_only_once() {
 
 		// Create Static Organization name
 		const ORG_NAME: &'static [u8] = &[7];
-		let hash = sp_core::H256::zero();
+		let hash = create_task(1);
 
 		// Ensure organization can be created
 		assert_ok!(Dao::create_organization(Origin::signed(1), ORG_NAME.to_vec()));
@@ -613,11 +639,33 @@ fn can_add_task_to_organization_only_once() {
 		assert_noop!(Dao::add_tasks(Origin::signed(1), ORG_NAME.to_vec(), hash), Error::<Test>::TaskAlreadyExists);
 
 		// Check only 1 task was added
-		assert_eq!(Dao::organization_tasks(ORG_NAME.to_vec()).len(), 1);
+		assert_eq!(Dao::organization_tasks(org_key(ORG_NAME)).len(), 1);
+
+		// Ensure the task can't be added to another organization of the founder
+		assert_ok!(Dao::create_organization(Origin::signed(1), vec![8]));
+		assert_noop!(Dao::add_tasks(Origin::signed(1), vec![8], hash), Error::<Test>::TaskAlreadyExists);
+		assert_eq!(Dao::task_organization(hash), Some(org_key(ORG_NAME)));
 
 	});
 }
 
+#[test]
+fn only_task_initiator_can_add_task_to_organization() {
+	new_test_ext().execute_with(|| {
+
+		// Create Static Organization name
+		const ORG_NAME: &'static [u8] = &[7];
+		let hash = create_task(2);
+
+		// Ensure organization can be created
+		assert_ok!(Dao::create_organization(Origin::signed(1), ORG_NAME.to_vec()));
+
+		// Throw error if the founder adds a task created by someone else or a task that does not exist
+		assert_noop!(Dao::add_tasks(Origin::signed(1), ORG_NAME.to_vec(), hash), Error::<Test>::NotTaskInitiator);
+		assert_noop!(Dao::add_tasks(Origin::signed(1), ORG_NAME.to_vec(), sp_core::H256::zero()), Error::<Test>::TaskNotExist);
+	});
+}
+
 #[test]
 fn only_creator_can_add_task_to_organization() {
 	new_test_ext().execute_with(|| {
@@ -652,7 +700,7 @@ fn can_remove_task_from_organization() {
 
 		// Create Static Organization name
 		const ORG_NAME: &'static [u8] = &[7];
-		let hash = sp_core::H256::zero();
+		let hash = create_task(1);
 
 		// Ensure organization can be created
 		assert_ok!(Dao::create_organization(Origin::signed(1), ORG_NAME.to_vec()));
@@ -661,13 +709,14 @@ fn can_remove_task_from_organization() {
 		assert_ok!(Dao::add_tasks(Origin::signed(1), ORG_NAME.to_vec(), hash));
 
 		// Check only 1 task was added
-		assert_eq!(Dao::organization_tasks(ORG_NAME.to_vec()).len(), 1);
+		assert_eq!(Dao::organization_tasks(org_key(ORG_NAME)).len(), 1);
 
 		// Remove task from organization
 		assert_ok!(Dao::remove_tasks(Origin::signed(1), ORG_NAME.to_vec(), hash));
 
 		// Ensure the organization tasks are 0
-		assert_eq!(Dao::organization_tasks(ORG_NAME.to_vec()).len(), 0);
+		assert_eq!(Dao::organization_tasks(org_key(ORG_NAME)).len(), 0);
+		assert_eq!(Dao::task_organization(hash), None);
 
 	});
 }
@@ -678,7 +727,7 @@ fn can_remove_task_from_organization_only_once() {
 
 		// Create Static Organization name
 		const ORG_NAME: &'static [u8] = &[7];
-		let hash = sp_core::H256::zero();
+		let hash = create_task(1);
 
 		// Ensure organization can be created
 		assert_ok!(Dao::create_organization(Origin::signed(1), ORG_NAME.to_vec()));
@@ -721,4 +770,222 @@ fn can_not_remove_tasks_from_organization_that_does_not_exist() {
 		// Throw error if organization is not found
 		assert_noop!(Dao::remove_tasks(Origin::signed(2), Vec::new(), hash), Error::<Test>::InvalidOrganization);
 	});
-}
\ No newline at end of file
+}
+#[test]
+fn organization_members_arbitrate_its_tasks() {
+	new_test_ext().execute_with(|| {
+
+		// Create Static Organization name
+		const ORG_NAME: &'static [u8] = &[7];
+		let hash = create_task(1);
+
+		// Ensure tasks without organization have no arbitrators
+		assert_eq!(<Dao as TaskArbitrators<u64, sp_core::H256>>::arbitrators(&hash), Vec::<u64>::new());
+
+		assert_ok!(Dao::create_organization(Origin::signed(1), ORG_NAME.to_vec()));
+		assert_ok!(Dao::add_members(Origin::signed(1), ORG_NAME.to_vec(), 2));
+		assert_ok!(Dao::add_tasks(Origin::signed(1), ORG_NAME.to_vec(), hash));
+
+		// Ensure the organization members arbitrate the task
+		assert_eq!(<Dao as TaskArbitrators<u64, sp_core::H256>>::arbitrators(&hash), vec![1, 2]);
+	});
+}
+
+#[test]
+fn migration_deduplicates_organization_tasks() {
+	new_test_ext().execute_with(|| {
+
+		// Create Static Organization name
+		const ORG_NAME: &'static [u8] = &[7];
+		let hash = sp_core::H256::repeat_byte(1);
+
+		// Colliding task ids could be added to an organization twice before storage version 1
+		assert_ok!(Dao::create_organization(Origin::signed(1), ORG_NAME.to_vec()));
+		crate::pallet::OrganizationTasks::<Test>::insert(org_key(ORG_NAME), BoundedVec::try_from(vec![hash, hash]).unwrap());
+		StorageVersion::new(0).put::<Dao>();
+
+		Dao::migrate_organization_tasks();
+
+		// Ensure ids that were not re-keyed are kept once and both pallets are migrated
+		assert_eq!(Dao::organization_tasks(org_key(ORG_NAME)).to_vec(), vec![hash]);
+		assert_eq!(Dao::on_chain_storage_version(), 1);
+		assert_eq!(pallet_task::Pallet::<Test>::on_chain_storage_version(), 1);
+	});
+}
+
+#[test]
+fn dao_storage_is_bounded() {
+	new_test_ext().execute_with(|| {
+
+		// Ensure oversize visions and organization names are refused
+		assert_noop!(Dao::create_vision(Origin::signed(1), vec![1; 33]), Error::<Test>::VisionTooLong);
+		assert_noop!(Dao::create_organization(Origin::signed(1), vec![1; 9]), Error::<Test>::OrgNameTooLong);
+
+		// Ensure organizations can't grow past the member limit
+		const ORG_NAME: &'static [u8] = &[7];
+		assert_ok!(Dao::create_organization(Origin::signed(1), ORG_NAME.to_vec()));
+		for account in 2..6 {
+			assert_ok!(Dao::add_members(Origin::signed(1), ORG_NAME.to_vec(), account));
+		}
+		assert_noop!(Dao::add_members(Origin::signed(1), ORG_NAME.to_vec(), 6), Error::<Test>::ExceedMaxMembers);
+	});
+}
+
+#[test]
+fn migration_bounds_dao_storage() {
+	new_test_ext().execute_with(|| {
+
+		// Store organizations and visions the way they were stored before storage version 2
+		let pallet = <Dao as PalletInfoAccess>::name().as_bytes();
+		let long_name = vec![1u8; 9];
+		put_storage_value(pallet, b"Organization", &Twox64Concat::hash(&long_name.encode()), vec![1u64]);
+		put_storage_value(pallet, b"Organization", &Twox64Concat::hash(&vec![7u8].encode()), (1u64..8).collect::<Vec<u64>>());
+		put_storage_value(pallet, b"Vision", &Blake2_128Concat::hash(&vec![1u8; 33].encode()), (1u64, 1u64));
+		put_storage_value(pallet, b"OrganizationCount", &[], 2u64);
+		put_storage_value(pallet, b"VisionCount", &[], 1u64);
+		StorageVersion::new(1).put::<Dao>();
+
+		Dao::migrate_bounded_storage();
+
+		// Ensure oversize keys are removed and oversize lists truncated
+		assert_eq!(Dao::organization(org_key(&[7])).to_vec(), vec![1, 2, 3, 4, 5]);
+		assert_eq!(Dao::organization_count(), 1);
+		assert_eq!(Dao::vision_count(), 0);
+		assert_eq!(Dao::on_chain_storage_version(), 2);
+	});
+}
+
+#[test]
+fn organizations_collect_commission_on_their_tasks() {
+	new_test_ext().execute_with(|| {
+
+		// Create Static Organization name
+		const ORG_NAME: &'static [u8] = &[7];
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&1, 1000);
+		assert_ok!(Profile::create_profile(Origin::signed(1), b"Alice".to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), b"Bob".to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), b"Task".to_vec(), Vec::new(), 100, 50, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+
+		// Ensure tasks without organization pay the default commission to the task treasury
+		assert_eq!(Task::commission_of(&hash), (Task::treasury_account(), Permill::from_percent(10)));
+
+		assert_ok!(Dao::create_organization(Origin::signed(1), ORG_NAME.to_vec()));
+		assert_ok!(Dao::add_tasks(Origin::signed(1), ORG_NAME.to_vec(), hash));
+		let treasury = Dao::treasury_account(&org_key(ORG_NAME));
+		assert_eq!(Task::commission_of(&hash), (treasury, Permill::from_percent(10)));
+
+		// Ensure only the founder overrides the commission, up to the maximum commission
+		assert_noop!(Dao::set_commission(Origin::signed(2), ORG_NAME.to_vec(), Some(Permill::from_percent(20))), Error::<Test>::NotOrganizationCreator);
+		assert_noop!(Dao::set_commission(Origin::signed(1), ORG_NAME.to_vec(), Some(Permill::from_percent(51))), Error::<Test>::CommissionTooHigh);
+		assert_ok!(Dao::set_commission(Origin::signed(1), ORG_NAME.to_vec(), Some(Permill::from_percent(20))));
+		System::assert_last_event(Event::Dao(crate::Event::CommissionSet(1, ORG_NAME.to_vec(), Some(Permill::from_percent(20)))));
+
+		// Ensure the payout is split between the volunteer and the organization treasury
+		assert_ok!(Task::apply_for_task(Origin::signed(2), hash, None, sp_core::H256::zero()));
+		assert_ok!(Task::assign_to_applicant(Origin::signed(1), hash, 2));
+		assert_ok!(Task::submit_deliverable(Origin::signed(2), hash, vec![1]));
+		assert_ok!(Task::complete_task(Origin::signed(2), hash));
+		assert_ok!(Task::accept_task(Origin::signed(1), hash, 0));
+		System::assert_has_event(Event::Task(pallet_task::Event::BudgetPaid(2, hash, 80)));
+		System::assert_has_event(Event::Task(pallet_task::Event::CommissionPaid(treasury, hash, 20)));
+		assert_eq!(Balances::free_balance(&2), 80);
+		assert_eq!(Balances::free_balance(&treasury), 20);
+		assert_eq!(Balances::free_balance(&1), 900);
+
+		// Ensure the override and the tasks are cleared with the organization
+		assert_ok!(Dao::dissolve_organization(Origin::signed(1), ORG_NAME.to_vec()));
+		assert_eq!(Dao::organization_commission(org_key(ORG_NAME)), None);
+		assert_eq!(Dao::task_organization(hash), None);
+		assert_eq!(Task::commission_of(&hash), (Task::treasury_account(), Permill::from_percent(10)));
+	});
+}
+
+#[test]
+fn reputation_is_scoped_to_the_task_organization() {
+	new_test_ext().execute_with(|| {
+
+		// Create Static Organization name
+		const ORG_NAME: &'static [u8] = &[7];
+		let hash = create_task(1);
+
+		// Ensure tasks without organization are not scoped
+		assert_eq!(Task::reputation_context(&hash, 100, None).scope, None);
+
+		// Ensure tasks of an organization are scoped to its account
+		assert_ok!(Dao::create_organization(Origin::signed(1), ORG_NAME.to_vec()));
+		assert_ok!(Dao::add_tasks(Origin::signed(1), ORG_NAME.to_vec(), hash));
+		assert_eq!(Task::reputation_context(&hash, 100, None).scope, Some(Dao::treasury_account(&org_key(ORG_NAME))));
+
+		// Ensure the scope follows the task out of the organization
+		assert_ok!(Dao::remove_tasks(Origin::signed(1), ORG_NAME.to_vec(), hash));
+		assert_eq!(Task::reputation_context(&hash, 100, None).scope, None);
+	});
+}
+
+#[test]
+fn tasks_leave_their_organization_when_closed_expired_or_removed() {
+	new_test_ext().execute_with(|| {
+
+		// Create Static Organization name
+		const ORG_NAME: &'static [u8] = &[7];
+		System::set_block_number(1);
+		let closed = create_task(1);
+		assert_ok!(Task::create_task(Origin::signed(1), b"Task".to_vec(), Vec::new(), 100, 10, Vec::new()));
+		let expired = *Task::tasks_owned(1).last().unwrap();
+		assert_ok!(Task::create_task(Origin::signed(1), b"Task".to_vec(), Vec::new(), 100, 50, Vec::new()));
+		let removed = *Task::tasks_owned(1).last().unwrap();
+		assert_ok!(Dao::create_organization(Origin::signed(1), ORG_NAME.to_vec()));
+		for hash in [closed, expired, removed] {
+			assert_ok!(Dao::add_tasks(Origin::signed(1), ORG_NAME.to_vec(), hash));
+		}
+		assert_eq!(Dao::organization_tasks(org_key(ORG_NAME)).len(), 3);
+
+		// Ensure a closed task leaves the organization, but its receipt keeps the reputation scope
+		assert_ok!(Profile::create_profile(Origin::signed(2), b"Bob".to_vec(), Vec::new()));
+		assert_ok!(Task::apply_for_task(Origin::signed(2), closed, None, sp_core::H256::zero()));
+		assert_ok!(Task::assign_to_applicant(Origin::signed(1), closed, 2));
+		assert_ok!(Task::submit_deliverable(Origin::signed(2), closed, vec![1]));
+		assert_ok!(Task::complete_task(Origin::signed(2), closed));
+		assert_ok!(Task::accept_task(Origin::signed(1), closed, 0));
+		assert_eq!(Dao::task_organization(closed), None);
+		assert_eq!(Dao::organization_tasks(org_key(ORG_NAME)).to_vec(), vec![expired, removed]);
+		assert_eq!(Task::reputation_context(&closed, 100, None).scope, Some(Dao::treasury_account(&org_key(ORG_NAME))));
+
+		// Ensure an expired task leaves the organization
+		System::set_block_number(10);
+		Task::expire_tasks(10);
+		assert_eq!(Dao::task_organization(expired), None);
+		assert_eq!(Dao::organization_tasks(org_key(ORG_NAME)).to_vec(), vec![removed]);
+
+		// Ensure a removed task leaves the organization
+		assert_ok!(Task::remove_task(Origin::signed(1), removed));
+		assert_eq!(Dao::task_organization(removed), None);
+		assert!(Dao::organization_tasks(org_key(ORG_NAME)).is_empty());
+	});
+}
+
+#[test]
+fn migration_indexes_task_organizations() {
+	new_test_ext().execute_with(|| {
+
+		// Tasks could be added to several organizations and by anyone before storage version 3
+		let own = create_task(1);
+		let foreign = create_task(2);
+		assert_ok!(Dao::create_organization(Origin::signed(1), vec![7]));
+		assert_ok!(Dao::create_organization(Origin::signed(1), vec![8]));
+		crate::pallet::OrganizationTasks::<Test>::insert(org_key(&[7]), BoundedVec::try_from(vec![own, foreign]).unwrap());
+		crate::pallet::OrganizationTasks::<Test>::insert(org_key(&[8]), BoundedVec::try_from(vec![own]).unwrap());
+		StorageVersion::new(2).put::<Dao>();
+
+		Dao::migrate_task_organizations();
+
+		// Ensure each task the founder created belongs to one organization and others are dropped
+		let owner = Dao::task_organization(own).unwrap();
+		assert_eq!(Dao::organization_tasks(&owner).to_vec(), vec![own]);
+		assert_eq!(Dao::organization_tasks(org_key(&[7])).len() + Dao::organization_tasks(org_key(&[8])).len(), 1);
+		assert_eq!(Dao::task_organization(foreign), None);
+		assert_eq!(Dao::on_chain_storage_version(), 3);
+	});
+}
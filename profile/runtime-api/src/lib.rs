@@ -0,0 +1,41 @@
+// This file is part of Substrate.
+
+// Copyright UNIVERSALDOT FOUNDATION
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the profile pallet.
+//!
+//! Runtimes implement the API by forwarding to the pallet, e.g.
+//! `fn reputation(owner: AccountId) -> u32 { Profile::reputation_of(&owner) }`.
+//! Ranked accounts come from `Profile::endorsed_accounts(&skill)`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait ProfileApi<AccountId> where
+		AccountId: Codec,
+	{
+		/// Reputation of the given account, decayed to the current block.
+		/// Zero for accounts without a profile.
+		fn reputation(owner: AccountId) -> u32;
+
+		/// Accounts endorsed for the given skill with their number of endorsements,
+		/// most endorsed first.
+		fn endorsed_accounts(skill: Vec<u8>) -> Vec<(AccountId, u32)>;
+	}
+}
@@ -21,8 +21,9 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as PalletProfile;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller, vec};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller, vec};
 use frame_system::RawOrigin;
+use sp_std::vec::Vec;
 
 use frame_support::{
 	traits::{Currency}};
@@ -48,11 +49,30 @@ fn create_profile_info<T: Config>(_num_fields: u32) -> Profile<T> {
 		interests: interests,
 		balance: Some(balance),
 		reputation: u32::MAX,
+		last_updated: frame_system::Pallet::<T>::block_number(),
 	};
 
 	return info
 }
 
+// Helper function to declare the given skills and endorse each of them as often as allowed
+fn create_endorsed_skills<T: Config>(who: &T::AccountId, skills: Vec<Vec<u8>>) {
+	for skill in skills.iter() {
+		T::SkillRegistry::register(skill);
+	}
+	let declared = skills.iter().map(|skill| (skill.clone(), 3)).collect();
+	let _ = PalletProfile::<T>::set_skills(RawOrigin::Signed(who.clone()).into(), declared);
+
+	for i in 0 .. T::MaxEndorsements::get() {
+		let endorser: T::AccountId = account("endorser", i, 0);
+		let _ = PalletProfile::<T>::create_profile(RawOrigin::Signed(endorser.clone()).into(), vec![0u8], vec![0u8]);
+		let deposits = T::EndorsementDeposit::get() * (skills.len() as u32).into();
+		T::Currency::make_free_balance_be(&endorser, deposits + T::Currency::minimum_balance());
+		for skill in skills.iter() {
+			let _ = PalletProfile::<T>::endorse_skill(RawOrigin::Signed(endorser.clone()).into(), who.clone(), skill.clone(), StakeKind::Deposit);
+		}
+	}
+}
 
 benchmarks! {
 	// ** Template for testing extrinsic functions ** //
@@ -116,8 +136,9 @@ benchmarks! {
 		let interests = vec![0u8, s as u8];
 		let username = vec![0u8, s as u8];
 
-		// before we delete profile, profile must be created
-		let _ = PalletProfile::<T>::create_profile(RawOrigin::Signed(create_account_caller).into(), username, interests);
+		// before we delete profile, profile must be created, with its skills endorsed as often as allowed
+		let _ = PalletProfile::<T>::create_profile(RawOrigin::Signed(create_account_caller.clone()).into(), username, interests);
+		create_endorsed_skills::<T>(&create_account_caller, (0 .. T::MaxSkills::get()).map(|i| vec![1u8, i as u8]).collect());
 
 	}: remove_profile(RawOrigin::Signed(delete_account_caller))
 	
@@ -126,6 +147,70 @@ benchmarks! {
 		let caller: T::AccountId = whitelisted_caller();
 		assert_last_event::<T>(Event::<T>::ProfileDeleted { who: caller }.into());
 	}
+
+	set_skills {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+
+		// Declare as many registered skills as allowed
+		let s in 1 .. T::MaxSkills::get();
+		let skills: Vec<(Vec<u8>, u8)> = (0..s).map(|i| {
+			let skill = vec![1u8, i as u8];
+			T::SkillRegistry::register(&skill);
+			(skill, 3)
+		}).collect();
+		let _ = PalletProfile::<T>::create_profile(RawOrigin::Signed(caller.clone()).into(), vec![0u8], vec![0u8]);
+
+		// Every previous skill is dropped and returns its endorsements
+		create_endorsed_skills::<T>(&caller, (0 .. T::MaxSkills::get()).map(|i| vec![2u8, i as u8]).collect());
+
+	}: set_skills(RawOrigin::Signed(caller.clone()), skills)
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::SkillsUpdated { who: caller }.into());
+	}
+
+	endorse_skill {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let endorsee: T::AccountId = account("endorsee", 0, 0);
+		let skill = vec![1u8];
+		T::SkillRegistry::register(&skill);
+
+		// Both accounts have a profile and the endorsee declared the skill
+		let _ = PalletProfile::<T>::create_profile(RawOrigin::Signed(caller.clone()).into(), vec![0u8], vec![0u8]);
+		let _ = PalletProfile::<T>::create_profile(RawOrigin::Signed(endorsee.clone()).into(), vec![0u8], vec![0u8]);
+		let _ = PalletProfile::<T>::set_skills(RawOrigin::Signed(endorsee.clone()).into(), vec![(skill.clone(), 3)]);
+		T::Currency::make_free_balance_be(&caller, T::EndorsementDeposit::get() + T::Currency::minimum_balance());
+
+	}: endorse_skill(RawOrigin::Signed(caller.clone()), endorsee.clone(), skill.clone(), StakeKind::Deposit)
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::SkillEndorsed { endorser: caller, who: endorsee, skill }.into());
+	}
+
+	revoke_endorsement {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let endorsee: T::AccountId = account("endorsee", 0, 0);
+		let skill = vec![1u8];
+		T::SkillRegistry::register(&skill);
+
+		// The caller endorsed the skill with a deposit
+		let _ = PalletProfile::<T>::create_profile(RawOrigin::Signed(caller.clone()).into(), vec![0u8], vec![0u8]);
+		let _ = PalletProfile::<T>::create_profile(RawOrigin::Signed(endorsee.clone()).into(), vec![0u8], vec![0u8]);
+		let _ = PalletProfile::<T>::set_skills(RawOrigin::Signed(endorsee.clone()).into(), vec![(skill.clone(), 3)]);
+		T::Currency::make_free_balance_be(&caller, T::EndorsementDeposit::get() + T::Currency::minimum_balance());
+		let _ = PalletProfile::<T>::endorse_skill(RawOrigin::Signed(caller.clone()).into(), endorsee.clone(), skill.clone(), StakeKind::Deposit);
+
+	}: revoke_endorsement(RawOrigin::Signed(caller.clone()), endorsee.clone(), skill.clone())
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::EndorsementRevoked { endorser: caller, who: endorsee, skill }.into());
+	}
 }
 
 impl_benchmark_test_suite!(PalletProfile, crate::mock::new_test_ext(), crate::mock::Test,);
\ No newline at end of file
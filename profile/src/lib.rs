@@ -31,11 +31,45 @@
 //!
 //! ### Public Functions
 //!
-//! - `create_profile` - Function used to create a new user profile.
+//! - `create_profile` - Function used to create a new user profile. Usernames and
+//! interests are limited to `MaxUsernameLength` and `MaxInterestsLength` bytes.
 //! 
 //! - `update_profile` - Function used to update an already existing user profile.
 //! 
-//! - `remove_profile` - Function used to delete an existing user profile.
+//! - `remove_profile` - Function used to delete an existing user profile. Endorsements of its
+//! skills are removed and their stakes returned to the endorsers.
+//!
+//! - `set_skills` - Function used to declare the skills of a profile, each with a level from
+//! 1 to 5. Skills are drawn from the `SkillRegistry`, such as the skill tags of tasks, and
+//! are limited to `MaxSkills`. Endorsements of dropped skills are returned to the endorsers.
+//!
+//! - `endorse_skill` - Function used to endorse a declared skill of another profile by
+//! staking `EndorsementReputation` or reserving `EndorsementDeposit`. A skill of an account
+//! has at most `MaxEndorsements` endorsements.
+//!
+//! - `revoke_endorsement` - Function used to revoke an endorsement and get its stake back.
+//!
+//! Endorsements are counted per skill, so accounts can be ranked by their endorsed skills
+//! with `endorsed_accounts`.
+//!
+//! Reputation is computed by the configured `ReputationHandler` from the context of the work,
+//! such as the budget paid, the rating, the DAO and the lateness. The `Linear`,
+//! `BudgetWeighted` and `Logarithmic` models are provided, and `()` is linear.
+//!
+//! Reputation decays by half every `ReputationHalfLife` blocks. The decay is applied lazily
+//! when the reputation of a profile changes, and `reputation_of` returns the decayed score,
+//! which runtimes expose through the `ProfileApi` runtime API of `pallet-profile-runtime-api`.
+//!
+//! Abandoning a task, losing a dispute and missing a deadline are penalized by `penalize`
+//! with `AbandonPenalty`, `DisputePenalty` and `DeadlinePenalty`, each emitting an event with
+//! its reason. Penalties never take the reputation below `ReputationFloor`, and profiles below
+//! `MinReputation` can not take new tasks. New profiles start at the higher of both, which is
+//! also the level decay never goes below, so only penalized accounts fall under the threshold.
+//! A `MinReputation` at or below the floor disables the threshold. A profile that is deleted
+//! below the starting reputation gets its reputation back when it is created again.
+//!
+//! Accounts are rated by the other party of the tasks they worked on. The count and sum of
+//! the ratings and their average weighted by the value of each task are kept per account.
 //!
 //! ## Related Modules
 //!
@@ -59,27 +93,179 @@ pub mod pallet {
 	use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
 	use frame_system::pallet_prelude::*;
 	use frame_support::{
-		sp_runtime::traits::Hash, 
-		traits::{Currency}};
+		sp_runtime::traits::{Hash, SaturatedConversion, Saturating, Zero}, 
+		traits::{Currency, GetStorageVersion, ReservableCurrency, StorageVersion}};
 	use scale_info::TypeInfo;
-	use sp_std::vec::Vec;
+	use sp_std::{convert::{TryFrom, TryInto}, marker::PhantomData, vec::Vec};
+
+	/// Storage version 1 bounds the name and interests of profiles.
+	/// Storage version 2 records when the reputation of a profile was last decayed.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 
 	// Account, Balance are used in Profile Struct
 	type AccountOf<T> = <T as frame_system::Config>::AccountId;
 	type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type SkillOf<T> = BoundedVec<u8, <T as Config>::MaxSkillLength>;
 
 
 	// Struct for holding Profile information.
-	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
 	pub struct Profile<T: Config> {
 		pub owner: AccountOf<T>,
-		pub name: Vec<u8>,
-		pub interests: Vec<u8>,
+		pub name: BoundedVec<u8, T::MaxUsernameLength>,
+		pub interests: BoundedVec<u8, T::MaxInterestsLength>,
 		pub balance: Option<BalanceOf<T>>,
 		pub reputation: u32,
+		/// Block up to which the decay of the reputation has been applied.
+		pub last_updated: T::BlockNumber,
+	}
+
+	// Struct for holding the aggregate of the ratings an account received.
+	#[derive(Clone, Encode, Decode, Default, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct RatingSummary {
+		pub count: u32,
+		pub sum: u32,
+		pub weighted_sum: u128,
+		pub total_weight: u128,
+		/// Average score weighted by the value of the rated tasks, in hundredths.
+		pub weighted_average: u32,
+	}
+
+	/// What an endorser puts at stake for an endorsement.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum StakeKind {
+		/// Stake `EndorsementReputation` of the endorser's reputation.
+		Reputation,
+		/// Reserve `EndorsementDeposit` of the endorser's balance.
+		Deposit,
+	}
+
+	/// Stake held for an endorsement until it is revoked.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum EndorsementStake<Balance> {
+		Reputation(u32),
+		Deposit(Balance),
+	}
+
+	/// Registry of the skills profiles can declare, such as the skill tags of tasks.
+	pub trait SkillRegistry {
+		fn is_registered(skill: &[u8]) -> bool;
+
+		/// Registers a skill for benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		fn register(skill: &[u8]);
+	}
+
+	/// Every skill is registered.
+	impl SkillRegistry for () {
+		fn is_registered(_skill: &[u8]) -> bool {
+			true
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn register(_skill: &[u8]) {}
+	}
+
+	/// Reasons for which the reputation of a profile is penalized.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum PenaltyReason {
+		/// The volunteer abandoned a task or was unassigned from it.
+		AbandonedTask,
+		/// The party lost a dispute about a task.
+		LostDispute,
+		/// The volunteer did not complete a task by its deadline.
+		MissedDeadline,
+	}
+
+	/// Context of a change in reputation, such as completing or being rated for a task.
+	#[derive(Clone, PartialEq, RuntimeDebug)]
+	pub struct ReputationContext<AccountId> {
+		/// Amount paid for the work, in the smallest unit of its currency.
+		pub budget: u128,
+		/// Score from 1 to 5 the account was rated with, if the change is caused by a rating.
+		pub rating: Option<u8>,
+		/// Account of the DAO the work was done for, if any.
+		pub scope: Option<AccountId>,
+		/// Blocks the work was closed after its deadline.
+		pub lateness: u32,
+	}
+
+	impl<AccountId> ReputationContext<AccountId> {
+		// Completing work earns `points`, a rating adds or removes up to `points` around a neutral 3
+		fn scale(&self, points: i64) -> i64 {
+			match self.rating {
+				Some(score) => points.saturating_mul(i64::from(score) - 3),
+				None => points,
+			}
+		}
+	}
+
+	/// Scoring model that computes the change in reputation of an account.
+	pub trait ReputationHandler<AccountId> {
+		fn reputation_change(who: &AccountId, context: &ReputationContext<AccountId>) -> i64;
+	}
+
+	/// Every task is worth one point, whatever its size.
+	pub struct Linear;
+
+	impl<AccountId> ReputationHandler<AccountId> for Linear {
+		fn reputation_change(_who: &AccountId, context: &ReputationContext<AccountId>) -> i64 {
+			context.scale(1)
+		}
+	}
+
+	/// The default scoring model is linear.
+	impl<AccountId> ReputationHandler<AccountId> for () {
+		fn reputation_change(who: &AccountId, context: &ReputationContext<AccountId>) -> i64 {
+			Linear::reputation_change(who, context)
+		}
+	}
+
+	/// Tasks are worth one point per `Unit` of budget, and at least one point.
+	pub struct BudgetWeighted<Unit>(PhantomData<Unit>);
+
+	impl<AccountId, Unit: Get<u128>> ReputationHandler<AccountId> for BudgetWeighted<Unit> {
+		fn reputation_change(_who: &AccountId, context: &ReputationContext<AccountId>) -> i64 {
+			let points = context.budget.checked_div(Unit::get()).unwrap_or_default().max(1);
+			context.scale(points.try_into().unwrap_or(i64::MAX))
+		}
+	}
+
+	/// Tasks are worth one point plus the binary logarithm of their budget in `Unit`s,
+	/// so larger tasks earn more with diminishing returns.
+	pub struct Logarithmic<Unit>(PhantomData<Unit>);
+
+	impl<AccountId, Unit: Get<u128>> ReputationHandler<AccountId> for Logarithmic<Unit> {
+		fn reputation_change(_who: &AccountId, context: &ReputationContext<AccountId>) -> i64 {
+			let units = context.budget.checked_div(Unit::get()).unwrap_or_default();
+			// 1 + floor(log2(units + 1))
+			let points = i64::from(128 - units.saturating_add(1).leading_zeros());
+			context.scale(points)
+		}
+	}
+
+	// Layout of profiles before storage version 1, used by the migration.
+	#[derive(Decode)]
+	struct LegacyProfile<T: Config> {
+		owner: AccountOf<T>,
+		name: Vec<u8>,
+		interests: Vec<u8>,
+		balance: Option<BalanceOf<T>>,
+		reputation: u32,
+	}
+
+	// Layout of profiles in storage version 1, before reputation decayed.
+	#[derive(Decode)]
+	struct UndecayedProfile<T: Config> {
+		owner: AccountOf<T>,
+		name: BoundedVec<u8, T::MaxUsernameLength>,
+		interests: BoundedVec<u8, T::MaxInterestsLength>,
+		balance: Option<BalanceOf<T>>,
+		reputation: u32,
 	}
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
@@ -89,11 +275,72 @@ pub mod pallet {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
 		/// The Currency handler for the Profile pallet.
-		type Currency: Currency<Self::AccountId>;
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The maximum length of a username.
+		#[pallet::constant]
+		type MaxUsernameLength: Get<u32>;
+
+		/// The maximum length of the interests of a profile.
+		#[pallet::constant]
+		type MaxInterestsLength: Get<u32>;
+
+		/// Scoring model for changes in reputation.
+		type ReputationHandler: ReputationHandler<Self::AccountId>;
+
+		/// Number of blocks after which unchanged reputation is halved. Zero disables the decay.
+		#[pallet::constant]
+		type ReputationHalfLife: Get<Self::BlockNumber>;
+
+		/// The reputation lost for abandoning a task or being unassigned.
+		#[pallet::constant]
+		type AbandonPenalty: Get<u32>;
+
+		/// The reputation lost for losing a dispute.
+		#[pallet::constant]
+		type DisputePenalty: Get<u32>;
+
+		/// The reputation lost for missing the deadline of a task.
+		#[pallet::constant]
+		type DeadlinePenalty: Get<u32>;
+
+		/// The reputation below which penalties never go.
+		#[pallet::constant]
+		type ReputationFloor: Get<u32>;
+
+		/// The reputation a profile needs to take new tasks. New profiles start with at least
+		/// this reputation, a value at or below `ReputationFloor` never blocks anyone.
+		#[pallet::constant]
+		type MinReputation: Get<u32>;
+
+		/// Registry the skills of profiles are drawn from.
+		type SkillRegistry: SkillRegistry;
+
+		/// The maximum length of a skill.
+		#[pallet::constant]
+		type MaxSkillLength: Get<u32>;
+
+		/// The maximum number of skills of a profile.
+		#[pallet::constant]
+		type MaxSkills: Get<u32>;
+
+		/// The reputation staked by an endorsement.
+		#[pallet::constant]
+		type EndorsementReputation: Get<u32>;
+
+		/// The deposit reserved by an endorsement.
+		#[pallet::constant]
+		type EndorsementDeposit: Get<BalanceOf<Self>>;
+
+		/// The maximum amount of endorsements of a skill of an account.
+		#[pallet::constant]
+		type MaxEndorsements: Get<u32>;
 	}
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -106,6 +353,31 @@ pub mod pallet {
 	/// Stores a Profile unique properties in a StorageMap.
 	pub(super) type Profiles<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Profile<T>>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn ratings)]
+	/// Aggregated ratings of an account, kept when the profile is deleted.
+	pub(super) type Ratings<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, RatingSummary, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn retained_reputation)]
+	/// Reputation of deleted profiles that were below the starting reputation, restored when the profile is created again.
+	pub(super) type RetainedReputation<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn skills)]
+	/// Skills of a profile with their self-declared level from 1 to 5.
+	pub(super) type Skills<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<(SkillOf<T>, u8), T::MaxSkills>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn endorsements)]
+	/// Stakes of the endorsements of a skill of an account [key: (endorsee, skill), endorser]
+	pub(super) type Endorsements<T: Config> = StorageDoubleMap<_, Blake2_128Concat, (T::AccountId, SkillOf<T>), Blake2_128Concat, T::AccountId, EndorsementStake<BalanceOf<T>>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn endorsement_count)]
+	/// Number of endorsements of accounts per skill [key: skill, endorsee]
+	pub(super) type EndorsementCount<T: Config> = StorageDoubleMap<_, Blake2_128Concat, SkillOf<T>, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
 	
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -119,6 +391,18 @@ pub mod pallet {
 		/// Profile was successfully updated.
 		ProfileUpdated { who: T::AccountId },
 
+		/// Reputation of a profile was penalized by the applied amount.
+		ReputationPenalized { who: T::AccountId, reason: PenaltyReason, penalty: u32 },
+
+		/// Skills of a profile were updated.
+		SkillsUpdated { who: T::AccountId },
+
+		/// A skill of an account was endorsed.
+		SkillEndorsed { endorser: T::AccountId, who: T::AccountId, skill: Vec<u8> },
+
+		/// An endorsement was revoked and its stake returned.
+		EndorsementRevoked { endorser: T::AccountId, who: T::AccountId, skill: Vec<u8> },
+
 	}
 
 	// Errors inform users that something went wrong.
@@ -134,6 +418,43 @@ pub mod pallet {
 		ProfileAlreadyCreated,
 		/// This Account has not yet created a profile.
 		NoProfileCreated,
+		/// The username is too long.
+		UsernameTooLong,
+		/// The interests are too long.
+		InterestsTooLong,
+		/// The skill is not in the registry.
+		SkillNotRegistered,
+		/// The skill is too long.
+		SkillTooLong,
+		/// Reached maximum number of skills.
+		TooManySkills,
+		/// A skill can only be declared once.
+		DuplicateSkill,
+		/// Skill levels must be between 1 and 5.
+		InvalidSkillLevel,
+		/// The account has not declared this skill.
+		SkillNotDeclared,
+		/// Accounts can not endorse themselves.
+		CannotEndorseSelf,
+		/// This account already endorsed the skill.
+		AlreadyEndorsed,
+		/// This account has not endorsed the skill.
+		NotEndorsed,
+		/// Not enough reputation above the floor to stake.
+		InsufficientReputation,
+		/// Not enough free balance to reserve the deposit.
+		InsufficientBalance,
+		/// Reached maximum number of endorsements of the skill.
+		TooManyEndorsements,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+
+		// Bound the name and interests of existing profiles and start their reputation decay.
+		fn on_runtime_upgrade() -> Weight {
+			Self::migrate_bounded_profiles().saturating_add(Self::migrate_decaying_profiles())
+		}
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -176,7 +497,10 @@ pub mod pallet {
 
 
 		/// Dispatchable call that enables every new actor to delete profile from storage.
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(
+			2 + T::MaxSkills::get() as Weight * (1 + T::MaxEndorsements::get() as Weight),
+			3 + T::MaxSkills::get() as Weight * (1 + 2 * T::MaxEndorsements::get() as Weight)
+		))]
 		pub fn remove_profile(origin: OriginFor<T>) -> DispatchResult {
 			
 			// Check that the extrinsic was signed and get the signer.
@@ -191,6 +515,57 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Dispatchable call that replaces the skills of a profile with registered skills and their level. [origin, skills]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(
+			3 + T::MaxSkills::get() as Weight * (1 + T::MaxEndorsements::get() as Weight),
+			1 + T::MaxSkills::get() as Weight * (1 + 2 * T::MaxEndorsements::get() as Weight)
+		))]
+		pub fn set_skills(origin: OriginFor<T>, skills: Vec<(Vec<u8>, u8)>) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let account = ensure_signed(origin)?;
+
+			// Call helper function to validate and store the skills
+			Self::change_skills(&account, skills)?;
+
+			// Emit an event.
+			Self::deposit_event(Event::SkillsUpdated { who: account });
+
+			Ok(())
+		}
+
+		/// Dispatchable call that endorses a declared skill of another profile by staking reputation or a deposit. [origin, who, skill, stake]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(5,3))]
+		pub fn endorse_skill(origin: OriginFor<T>, who: T::AccountId, skill: Vec<u8>, stake: StakeKind) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let account = ensure_signed(origin)?;
+
+			// Call helper function to stake and index the endorsement
+			Self::new_endorsement(&account, &who, &skill, stake)?;
+
+			// Emit an event.
+			Self::deposit_event(Event::SkillEndorsed { endorser: account, who, skill });
+
+			Ok(())
+		}
+
+		/// Dispatchable call that revokes an endorsement and returns its stake. [origin, who, skill]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,3))]
+		pub fn revoke_endorsement(origin: OriginFor<T>, who: T::AccountId, skill: Vec<u8>) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let account = ensure_signed(origin)?;
+
+			// Call helper function to remove the endorsement
+			Self::remove_endorsement(&account, &who, &skill)?;
+
+			// Emit an event.
+			Self::deposit_event(Event::EndorsementRevoked { endorser: account, who, skill });
+
+			Ok(())
+		}
+
 	}
 
 	// ** Helper internal functions ** //
@@ -207,10 +582,11 @@ pub mod pallet {
 			// Populate Profile struct
 			let profile = Profile::<T> {
 				owner: owner.clone(),
-				name: username,
-				interests: interests_vec,
+				name: username.try_into().map_err(|_| Error::<T>::UsernameTooLong)?,
+				interests: interests_vec.try_into().map_err(|_| Error::<T>::InterestsTooLong)?,
 				balance: Some(balance),
-				reputation: 0,
+				reputation: <RetainedReputation<T>>::take(owner).unwrap_or_else(Self::starting_reputation),
+				last_updated: <frame_system::Pallet<T>>::block_number(),
 			};
 
 			// Get hash of profile
@@ -233,9 +609,9 @@ pub mod pallet {
 			let mut profile = Self::profiles(owner).ok_or(<Error<T>>::NoUpdateAuthority)?;
 			
 			// Change interests of owner
-			profile.change_interests(new_interests);
+			profile.change_interests(new_interests.try_into().map_err(|_| <Error<T>>::InterestsTooLong)?);
 
-			profile.change_username(new_username);
+			profile.change_username(new_username.try_into().map_err(|_| <Error<T>>::UsernameTooLong)?);
 
 			// Get hash of profile
 			let profile_id = T::Hashing::hash_of(&profile);
@@ -251,10 +627,19 @@ pub mod pallet {
 		pub fn delete_profile(owner: &T::AccountId) -> Result<(), Error<T>> {
 			
 			// Ensure that only creator of profile can delete it
-			Self::profiles(owner).ok_or(<Error<T>>::NoDeletionAuthority)?;
+			let mut profile = Self::profiles(owner).ok_or(<Error<T>>::NoDeletionAuthority)?;
+
+			// Penalized profiles keep their reputation, so they can not be recreated to clear penalties
+			profile.decay_reputation();
+			if profile.reputation < Self::starting_reputation() {
+				<RetainedReputation<T>>::insert(owner, profile.reputation);
+			}
 			
-			// Remove profile from storage
+			// Remove profile and its skills from storage, returning the stakes of their endorsements
 			<Profiles<T>>::remove(owner);
+			for (skill, _) in <Skills<T>>::take(owner).iter() {
+				Self::clear_endorsements(owner, skill);
+			}
 
 			// Reduce profile count
 			let new_count = Self::profile_count().saturating_sub(1);
@@ -270,6 +655,7 @@ pub mod pallet {
 			let mut profile = Self::profiles(owner).ok_or(<Error<T>>::NoUpdateAuthority)?;
 
 			// Increase reputation
+			profile.decay_reputation();
 			profile.increase_reputation();
 
 			// Insert into storage a new profile
@@ -278,6 +664,262 @@ pub mod pallet {
 			Ok(())
 		}
 
+		// Public function that changes the reputation of a profile as computed by the ReputationHandler.
+		// Returns the applied change.
+		pub fn update_reputation(owner: &T::AccountId, context: &ReputationContext<T::AccountId>) -> Result<i64, Error<T>> {
+			// Get current profile
+			let mut profile = Self::profiles(owner).ok_or(<Error<T>>::NoUpdateAuthority)?;
+
+			// Apply the change without going below the floor
+			profile.decay_reputation();
+			let change = T::ReputationHandler::reputation_change(owner, context);
+			let magnitude: u32 = change.unsigned_abs().try_into().unwrap_or(u32::MAX);
+			if change >= 0 {
+				profile.reputation = profile.reputation.saturating_add(magnitude);
+			} else {
+				profile.decrease_reputation(magnitude);
+			}
+
+			// Insert into storage a new profile
+			<Profiles<T>>::insert(owner, profile);
+
+			Ok(change)
+		}
+
+		// Public function that applies the configured penalty for a reason to a profile.
+		// Returns the applied penalty, which is smaller when the profile reaches the floor.
+		pub fn penalize(owner: &T::AccountId, reason: PenaltyReason) -> Result<u32, Error<T>> {
+			let penalty = match reason {
+				PenaltyReason::AbandonedTask => T::AbandonPenalty::get(),
+				PenaltyReason::LostDispute => T::DisputePenalty::get(),
+				PenaltyReason::MissedDeadline => T::DeadlinePenalty::get(),
+			};
+
+			// Get current profile
+			let mut profile = Self::profiles(owner).ok_or(<Error<T>>::NoUpdateAuthority)?;
+
+			// Decrease reputation
+			profile.decay_reputation();
+			let applied = profile.decrease_reputation(penalty);
+
+			// Insert into storage a new profile
+			<Profiles<T>>::insert(owner, profile);
+
+			Self::deposit_event(Event::ReputationPenalized { who: owner.clone(), reason, penalty: applied });
+
+			Ok(applied)
+		}
+
+		// Reputation new profiles start with, which is enough to take tasks
+		pub fn starting_reputation() -> u32 {
+			T::ReputationFloor::get().max(T::MinReputation::get())
+		}
+
+		// Public function that checks if an account has enough reputation to take new tasks
+		pub fn can_take_tasks(owner: &T::AccountId) -> bool {
+			Self::reputation_of(owner) >= T::MinReputation::get()
+		}
+
+		// Public function that returns the reputation of an account, decayed to the current block.
+		// Accounts without a profile have no reputation.
+		pub fn reputation_of(owner: &T::AccountId) -> u32 {
+			Self::profiles(owner).map_or(0, |mut profile| {
+				profile.decay_reputation();
+				profile.reputation
+			})
+		}
+
+		// Replaces the skills of a profile. Skills must be registered, unique and have a level from 1 to 5.
+		// Endorsements of dropped skills are removed and their stakes returned.
+		pub fn change_skills(owner: &T::AccountId, skills: Vec<(Vec<u8>, u8)>) -> Result<(), Error<T>> {
+			ensure!(Profiles::<T>::contains_key(owner), <Error<T>>::NoProfileCreated);
+
+			let mut declared: BoundedVec<(SkillOf<T>, u8), T::MaxSkills> = BoundedVec::default();
+			for (skill, level) in skills {
+				ensure!(T::SkillRegistry::is_registered(&skill), <Error<T>>::SkillNotRegistered);
+				ensure!((1..=5).contains(&level), <Error<T>>::InvalidSkillLevel);
+				let skill: SkillOf<T> = skill.try_into().map_err(|_| <Error<T>>::SkillTooLong)?;
+				ensure!(!declared.iter().any(|(other, _)| *other == skill), <Error<T>>::DuplicateSkill);
+				declared.try_push((skill, level)).map_err(|_| <Error<T>>::TooManySkills)?;
+			}
+
+			for (skill, _) in Self::skills(owner).iter() {
+				if !declared.iter().any(|(kept, _)| kept == skill) {
+					Self::clear_endorsements(owner, skill);
+				}
+			}
+			<Skills<T>>::insert(owner, declared);
+
+			Ok(())
+		}
+
+		// Stakes reputation or a deposit of the endorser on a declared skill of another profile
+		pub fn new_endorsement(endorser: &T::AccountId, who: &T::AccountId, skill: &[u8], kind: StakeKind) -> Result<(), Error<T>> {
+			ensure!(endorser != who, <Error<T>>::CannotEndorseSelf);
+			let mut profile = Self::profiles(endorser).ok_or(<Error<T>>::NoProfileCreated)?;
+
+			// Only declared skills can be endorsed, once per endorser
+			let skill: SkillOf<T> = skill.to_vec().try_into().map_err(|_| <Error<T>>::SkillTooLong)?;
+			ensure!(Self::skills(who).iter().any(|(declared, _)| *declared == skill), <Error<T>>::SkillNotDeclared);
+			let key = (who.clone(), skill.clone());
+			ensure!(!<Endorsements<T>>::contains_key(&key, endorser), <Error<T>>::AlreadyEndorsed);
+			ensure!(Self::endorsement_count(&skill, who) < T::MaxEndorsements::get(), <Error<T>>::TooManyEndorsements);
+
+			// Staked reputation can not come from below the floor
+			let stake = match kind {
+				StakeKind::Reputation => {
+					let amount = T::EndorsementReputation::get();
+					profile.decay_reputation();
+					ensure!(profile.reputation >= T::ReputationFloor::get().saturating_add(amount), <Error<T>>::InsufficientReputation);
+					profile.reputation -= amount;
+					<Profiles<T>>::insert(endorser, profile);
+					EndorsementStake::Reputation(amount)
+				},
+				StakeKind::Deposit => {
+					let amount = T::EndorsementDeposit::get();
+					T::Currency::reserve(endorser, amount).map_err(|_| <Error<T>>::InsufficientBalance)?;
+					EndorsementStake::Deposit(amount)
+				},
+			};
+
+			<Endorsements<T>>::insert(&key, endorser, stake);
+			<EndorsementCount<T>>::mutate(&skill, who, |count| *count = count.saturating_add(1));
+
+			Ok(())
+		}
+
+		// Removes an endorsement and returns its stake. Staked reputation is lost with the endorser's profile.
+		pub fn remove_endorsement(endorser: &T::AccountId, who: &T::AccountId, skill: &[u8]) -> Result<(), Error<T>> {
+			let skill: SkillOf<T> = skill.to_vec().try_into().map_err(|_| <Error<T>>::SkillTooLong)?;
+			let stake = <Endorsements<T>>::take((who.clone(), skill.clone()), endorser).ok_or(<Error<T>>::NotEndorsed)?;
+			Self::return_stake(endorser, stake);
+
+			<EndorsementCount<T>>::mutate_exists(&skill, who, |maybe_count| {
+				*maybe_count = maybe_count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+			});
+
+			Ok(())
+		}
+
+		// Removes every endorsement of a skill of an account and returns their stakes
+		fn clear_endorsements(who: &T::AccountId, skill: &SkillOf<T>) {
+			for (endorser, stake) in <Endorsements<T>>::drain_prefix((who.clone(), skill.clone())) {
+				Self::return_stake(&endorser, stake);
+				Self::deposit_event(Event::EndorsementRevoked { endorser, who: who.clone(), skill: skill.to_vec() });
+			}
+			<EndorsementCount<T>>::remove(skill, who);
+		}
+
+		// Returns the stake of an endorsement to the endorser
+		fn return_stake(endorser: &T::AccountId, stake: EndorsementStake<BalanceOf<T>>) {
+			match stake {
+				EndorsementStake::Reputation(amount) => {
+					if let Some(mut profile) = Self::profiles(endorser) {
+						profile.decay_reputation();
+						profile.reputation = profile.reputation.saturating_add(amount);
+						<Profiles<T>>::insert(endorser, profile);
+					}
+				},
+				EndorsementStake::Deposit(amount) => {
+					T::Currency::unreserve(endorser, amount);
+				},
+			}
+		}
+
+		// Public function that returns the number of endorsements of a skill of an account
+		pub fn endorsements_of(who: &T::AccountId, skill: &[u8]) -> u32 {
+			SkillOf::<T>::try_from(skill.to_vec()).map_or(0, |skill| Self::endorsement_count(&skill, who))
+		}
+
+		// Public function that ranks the accounts endorsed for a skill, most endorsed first
+		pub fn endorsed_accounts(skill: &[u8]) -> Vec<(T::AccountId, u32)> {
+			let mut accounts: Vec<(T::AccountId, u32)> = match SkillOf::<T>::try_from(skill.to_vec()) {
+				Ok(skill) => <EndorsementCount<T>>::iter_prefix(&skill).collect(),
+				Err(_) => Vec::new(),
+			};
+			accounts.sort_by(|a, b| b.1.cmp(&a.1));
+			accounts
+		}
+
+		// Public function that adds a rating to the aggregate of an account.
+		// Ratings of more valuable tasks have a larger weight.
+		pub fn add_rating(owner: &T::AccountId, score: u8, weight: u128) {
+			<Ratings<T>>::mutate(owner, |ratings| {
+				ratings.count = ratings.count.saturating_add(1);
+				ratings.sum = ratings.sum.saturating_add(score.into());
+				ratings.weighted_sum = ratings.weighted_sum.saturating_add(weight.saturating_mul(score.into()));
+				ratings.total_weight = ratings.total_weight.saturating_add(weight);
+				ratings.weighted_average = ratings.weighted_sum.saturating_mul(100)
+					.checked_div(ratings.total_weight)
+					.unwrap_or_default()
+					.try_into()
+					.unwrap_or(u32::MAX);
+			});
+		}
+
+		// Truncates names and interests of profiles stored before storage version 1
+		// to the configured limits.
+		pub fn migrate_bounded_profiles() -> Weight {
+			if Self::on_chain_storage_version() >= 1 {
+				return 0;
+			}
+
+			let mut reads: Weight = 1;
+			let mut writes: Weight = 1;
+
+			<Profiles<T>>::translate::<LegacyProfile<T>, _>(|owner, mut profile| {
+				reads += 1;
+				writes += 1;
+				if profile.name.len() > T::MaxUsernameLength::get() as usize ||
+					profile.interests.len() > T::MaxInterestsLength::get() as usize {
+					log::warn!("truncating oversize name or interests of profile {:?}", owner);
+				}
+				profile.name.truncate(T::MaxUsernameLength::get() as usize);
+				profile.interests.truncate(T::MaxInterestsLength::get() as usize);
+
+				Some(Profile {
+					owner: profile.owner,
+					name: profile.name.try_into().ok()?,
+					interests: profile.interests.try_into().ok()?,
+					balance: profile.balance,
+					reputation: profile.reputation,
+					last_updated: <frame_system::Pallet<T>>::block_number(),
+				})
+			});
+
+			STORAGE_VERSION.put::<Self>();
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		// Starts the reputation decay of profiles stored in storage version 1 at the current block.
+		pub fn migrate_decaying_profiles() -> Weight {
+			if Self::on_chain_storage_version() != 1 {
+				return 0;
+			}
+
+			let mut reads: Weight = 1;
+			let mut writes: Weight = 1;
+			let now = <frame_system::Pallet<T>>::block_number();
+
+			<Profiles<T>>::translate::<UndecayedProfile<T>, _>(|_owner, profile| {
+				reads += 1;
+				writes += 1;
+				Some(Profile {
+					owner: profile.owner,
+					name: profile.name,
+					interests: profile.interests,
+					balance: profile.balance,
+					reputation: profile.reputation,
+					last_updated: now,
+				})
+			});
+
+			STORAGE_VERSION.put::<Self>();
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
 		// Public function that check if user has a profile
 		pub fn has_profile(owner: &T::AccountId) -> Result<bool, Error<T>>  {
 
@@ -288,21 +930,45 @@ pub mod pallet {
 		}
 	}
 
-	// Change the reputation on a Profile (TODO MVP2: Improve reputation functions)
+	// Change the reputation on a Profile
 	impl<T:Config> Profile<T> {
 		pub fn increase_reputation(&mut self) {
-			self.reputation += 1;
+			self.reputation = self.reputation.saturating_add(1);
+		}
+
+		// Decreases the reputation without going below the floor, and returns the applied decrease.
+		// Reputation that is already below the floor is kept.
+		pub fn decrease_reputation(&mut self, penalty: u32) -> u32 {
+			let floor = T::ReputationFloor::get();
+			let reputation = self.reputation.saturating_sub(penalty).max(floor).min(self.reputation);
+			let applied = self.reputation - reputation;
+			self.reputation = reputation;
+			applied
 		}
 
-		pub fn decrease_reputation(&mut self) {
-			self.reputation -= 1;
+		// Halves the reputation above the starting reputation for every full half-life since it was
+		// last decayed, so idle profiles do not fall below the threshold to take tasks.
+		// Blocks short of a full half-life carry over, so frequent updates do not stop the decay.
+		pub fn decay_reputation(&mut self) {
+			let now = <frame_system::Pallet<T>>::block_number();
+			let half_life = T::ReputationHalfLife::get();
+			let start = Pallet::<T>::starting_reputation();
+			if half_life.is_zero() || self.reputation <= start {
+				self.last_updated = now;
+				return;
+			}
+
+			let halvings = now.saturating_sub(self.last_updated) / half_life;
+			let above_start = (self.reputation - start).checked_shr(halvings.saturated_into::<u32>()).unwrap_or(0);
+			self.reputation = start + above_start;
+			self.last_updated = self.last_updated.saturating_add(halvings.saturating_mul(half_life));
 		}
 
-		pub fn change_interests(&mut self, new_interests: Vec<u8>) {
+		pub fn change_interests(&mut self, new_interests: BoundedVec<u8, T::MaxInterestsLength>) {
 			self.interests = new_interests;
 		}
 
-		pub fn change_username(&mut self, new_username: Vec<u8>) {
+		pub fn change_username(&mut self, new_username: BoundedVec<u8, T::MaxUsernameLength>) {
 			self.name = new_username;
 		}
 	} 
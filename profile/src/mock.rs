@@ -56,9 +56,58 @@ impl system::Config for Test {
 	type Version = ();
 }
 
+parameter_types! {
+	// Usernames are at most 16 bytes and interests at most 64 bytes
+	pub const MaxUsernameLength: u32 = 16;
+	pub const MaxInterestsLength: u32 = 64;
+	// Unchanged reputation halves every 10 blocks
+	pub const ReputationHalfLife: u64 = 10;
+	// Abandoning a task costs 1 reputation, a dispute 2 and a deadline 3
+	pub const AbandonPenalty: u32 = 1;
+	pub const DisputePenalty: u32 = 2;
+	pub const DeadlinePenalty: u32 = 3;
+	// Reputation never goes below 0 and every profile can take tasks
+	pub static ReputationFloor: u32 = 0;
+	pub static MinReputation: u32 = 0;
+	// Skills are at most 8 bytes and a profile has at most 3
+	pub const MaxSkillLength: u32 = 8;
+	pub const MaxSkills: u32 = 3;
+	// Endorsing stakes 2 reputation or reserves 5, a skill has at most 3 endorsements
+	pub const EndorsementReputation: u32 = 2;
+	pub const EndorsementDeposit: u64 = 5;
+	pub const MaxEndorsements: u32 = 3;
+}
+
+// Skills starting with 0 are not registered
+pub struct MockSkills;
+
+impl pallet_profile::SkillRegistry for MockSkills {
+	fn is_registered(skill: &[u8]) -> bool {
+		skill.first() != Some(&0)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn register(_skill: &[u8]) {}
+}
+
 impl pallet_profile::Config for Test {
 	type Event = Event;
 	type Currency =  Balances;
+	type MaxUsernameLength = MaxUsernameLength;
+	type MaxInterestsLength = MaxInterestsLength;
+	type ReputationHandler = ();
+	type ReputationHalfLife = ReputationHalfLife;
+	type AbandonPenalty = AbandonPenalty;
+	type DisputePenalty = DisputePenalty;
+	type DeadlinePenalty = DeadlinePenalty;
+	type ReputationFloor = ReputationFloor;
+	type MinReputation = MinReputation;
+	type SkillRegistry = MockSkills;
+	type MaxSkillLength = MaxSkillLength;
+	type MaxSkills = MaxSkills;
+	type EndorsementReputation = EndorsementReputation;
+	type EndorsementDeposit = EndorsementDeposit;
+	type MaxEndorsements = MaxEndorsements;
 }
 
 parameter_types! {
@@ -1,5 +1,6 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{mock::*, BudgetWeighted, Error, Linear, Logarithmic, PenaltyReason, ReputationContext, ReputationHandler, StakeKind};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, parameter_types, storage::unhashed, traits::{GetStorageVersion, ReservableCurrency, StorageVersion}};
 
 
 
@@ -30,9 +31,9 @@ fn verify_inputs_outputs_to_profile(){
 		let profile = Profile::profiles(10).expect("should found the profile");
 		
 		// Ensure that profile properties are assigned correctly
-		assert_eq!(profile.name, &[1]);
+		assert_eq!(profile.name.to_vec(), &[1]);
 		assert_eq!(profile.reputation, 0);
-		assert_eq!(profile.interests, &[7]);
+		assert_eq!(profile.interests.to_vec(), &[7]);
 	});
 }
 
@@ -149,8 +150,8 @@ fn user_can_update_profile() {
 		assert_eq!(Profile::profile_count(), 1);
 		
 		// Ensure that the values have been updated successfully
-		assert_eq!(profile.name, &[7]);
-		assert_eq!(profile.interests, &[6]);
+		assert_eq!(profile.name.to_vec(), &[7]);
+		assert_eq!(profile.interests.to_vec(), &[6]);
 
 	});
 }
@@ -173,4 +174,299 @@ fn user_can_only_update_own_profile() {
 		// Ensure another user can NOT update others profile.
 		assert_noop!(Profile::update_profile(Origin::signed(2), USERNAME.to_vec(), vec2), Error::<Test>::NoUpdateAuthority);
 	});
-}
\ No newline at end of file
+}
+#[test]
+fn penalty_does_not_reduce_reputation_below_zero() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Profile::create_profile(Origin::signed(1), Vec::new(), Vec::new()));
+		assert_ok!(Profile::add_reputation(&1));
+		assert_ok!(Profile::add_reputation(&1));
+
+		assert_eq!(Profile::penalize(&1, PenaltyReason::AbandonedTask).ok(), Some(1));
+		assert_eq!(Profile::profiles(1).expect("should find the profile").reputation, 1);
+
+		assert_eq!(Profile::penalize(&1, PenaltyReason::MissedDeadline).ok(), Some(1));
+		assert_eq!(Profile::profiles(1).expect("should find the profile").reputation, 0);
+		assert!(Profile::penalize(&2, PenaltyReason::LostDispute).is_err());
+	});
+}
+
+#[test]
+fn profile_fields_are_bounded() {
+	new_test_ext().execute_with(|| {
+		// Ensure oversize usernames and interests are refused
+		assert_noop!(Profile::create_profile(Origin::signed(1), vec![1; 17], Vec::new()), Error::<Test>::UsernameTooLong);
+		assert_noop!(Profile::create_profile(Origin::signed(1), Vec::new(), vec![1; 65]), Error::<Test>::InterestsTooLong);
+
+		assert_ok!(Profile::create_profile(Origin::signed(1), vec![1; 16], vec![1; 64]));
+		assert_noop!(Profile::update_profile(Origin::signed(1), vec![1; 17], Vec::new()), Error::<Test>::UsernameTooLong);
+	});
+}
+
+#[test]
+fn migration_truncates_oversize_profiles() {
+	new_test_ext().execute_with(|| {
+		// Store a profile the way it was stored before storage version 1
+		let legacy = (10u64, vec![1u8; 20], vec![7u8; 70], Some(5u64), 3u32).encode();
+		unhashed::put_raw(&crate::pallet::Profiles::<Test>::hashed_key_for(10), &legacy);
+		StorageVersion::new(0).put::<Profile>();
+
+		Profile::migrate_bounded_profiles();
+
+		// Ensure name and interests are truncated and the rest is kept
+		let profile = Profile::profiles(10).expect("should find the profile");
+		assert_eq!(profile.name.to_vec(), vec![1u8; 16]);
+		assert_eq!(profile.interests.to_vec(), vec![7u8; 64]);
+		assert_eq!(profile.balance, Some(5));
+		assert_eq!(profile.reputation, 3);
+		assert_eq!(profile.last_updated, System::block_number());
+		assert_eq!(Profile::on_chain_storage_version(), 2);
+	});
+}
+
+#[test]
+fn migration_starts_reputation_decay() {
+	new_test_ext().execute_with(|| {
+		// Store a profile the way it was stored in storage version 1
+		System::set_block_number(5);
+		let undecayed = (10u64, vec![1u8; 2], vec![7u8; 3], Some(5u64), 3u32).encode();
+		unhashed::put_raw(&crate::pallet::Profiles::<Test>::hashed_key_for(10), &undecayed);
+		StorageVersion::new(1).put::<Profile>();
+
+		Profile::migrate_decaying_profiles();
+
+		// Ensure the profile is kept and decays from the current block
+		let profile = Profile::profiles(10).expect("should find the profile");
+		assert_eq!(profile.reputation, 3);
+		assert_eq!(profile.last_updated, 5);
+		assert_eq!(Profile::on_chain_storage_version(), 2);
+	});
+}
+
+#[test]
+fn reputation_decays_by_half_life() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Profile::create_profile(Origin::signed(1), Vec::new(), Vec::new()));
+		for _ in 0..8 {
+			assert_ok!(Profile::add_reputation(&1));
+		}
+
+		// Ensure reads are decayed for every full half-life without writing
+		System::set_block_number(9);
+		assert_eq!(Profile::reputation_of(&1), 8);
+		System::set_block_number(11);
+		assert_eq!(Profile::reputation_of(&1), 4);
+		System::set_block_number(25);
+		assert_eq!(Profile::reputation_of(&1), 2);
+		assert_eq!(Profile::profiles(1).expect("should find the profile").reputation, 8);
+		assert_eq!(Profile::reputation_of(&2), 0);
+
+		// Ensure writes apply the decay and carry over the partial half-life
+		assert_ok!(Profile::add_reputation(&1));
+		let profile = Profile::profiles(1).expect("should find the profile");
+		assert_eq!((profile.reputation, profile.last_updated), (3, 21));
+		System::set_block_number(31);
+		assert_eq!(Profile::reputation_of(&1), 1);
+
+		// Ensure idle profiles do not decay below the threshold to take tasks
+		MinReputation::set(2);
+		System::set_block_number(41);
+		assert_eq!(Profile::reputation_of(&1), 2);
+		assert!(Profile::can_take_tasks(&1));
+	});
+}
+
+#[test]
+fn ratings_are_aggregated_by_weight() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Profile::ratings(1), Default::default());
+
+		// Ensure the average follows the weight of each rating
+		Profile::add_rating(&1, 5, 10);
+		Profile::add_rating(&1, 2, 30);
+		let ratings = Profile::ratings(1);
+		assert_eq!(ratings.count, 2);
+		assert_eq!(ratings.sum, 7);
+		assert_eq!(ratings.total_weight, 40);
+		assert_eq!(ratings.weighted_average, 275);
+	});
+}
+
+#[test]
+fn reputation_handlers_score_the_context() {
+	new_test_ext().execute_with(|| {
+		parameter_types! {
+			pub const Unit: u128 = 10;
+		}
+		let context = |budget, rating| ReputationContext::<u64> { budget, rating, scope: None, lateness: 0 };
+
+		// Ensure the linear model gives a point per task and rates around a neutral 3
+		assert_eq!(Linear::reputation_change(&1, &context(1_000, None)), 1);
+		assert_eq!(Linear::reputation_change(&1, &context(1_000, Some(5))), 2);
+		assert_eq!(Linear::reputation_change(&1, &context(1_000, Some(1))), -2);
+
+		// Ensure the budget weighted model gives at least a point
+		assert_eq!(BudgetWeighted::<Unit>::reputation_change(&1, &context(5, None)), 1);
+		assert_eq!(BudgetWeighted::<Unit>::reputation_change(&1, &context(70, None)), 7);
+		assert_eq!(BudgetWeighted::<Unit>::reputation_change(&1, &context(70, Some(2))), -7);
+
+		// Ensure the logarithmic model has diminishing returns
+		assert_eq!(Logarithmic::<Unit>::reputation_change(&1, &context(0, None)), 1);
+		assert_eq!(Logarithmic::<Unit>::reputation_change(&1, &context(10, None)), 2);
+		assert_eq!(Logarithmic::<Unit>::reputation_change(&1, &context(70, None)), 4);
+
+		// Ensure the configured model is applied without going below zero
+		assert!(Profile::update_reputation(&1, &context(0, None)).is_err());
+		assert_ok!(Profile::create_profile(Origin::signed(1), Vec::new(), Vec::new()));
+		assert_eq!(Profile::update_reputation(&1, &context(0, Some(4))).ok(), Some(1));
+		assert_eq!(Profile::update_reputation(&1, &context(0, Some(1))).ok(), Some(-2));
+		assert_eq!(Profile::profiles(1).expect("should find the profile").reputation, 0);
+	});
+}
+
+#[test]
+fn penalties_respect_the_floor_and_threshold() {
+	new_test_ext().execute_with(|| {
+		ReputationFloor::set(2);
+		MinReputation::set(3);
+		assert!(Profile::penalize(&1, PenaltyReason::AbandonedTask).is_err());
+
+		// Ensure new profiles start at the threshold, above the floor
+		assert_ok!(Profile::create_profile(Origin::signed(1), Vec::new(), Vec::new()));
+		assert_eq!(Profile::reputation_of(&1), 3);
+		assert!(Profile::can_take_tasks(&1));
+		for _ in 0..5 {
+			assert_ok!(Profile::add_reputation(&1));
+		}
+
+		// Ensure each reason is penalized by its amount and reported with the applied penalty
+		assert_eq!(Profile::penalize(&1, PenaltyReason::MissedDeadline).ok(), Some(3));
+		System::assert_last_event(Event::Profile(crate::Event::ReputationPenalized { who: 1, reason: PenaltyReason::MissedDeadline, penalty: 3 }));
+		assert_eq!(Profile::penalize(&1, PenaltyReason::LostDispute).ok(), Some(2));
+		assert!(Profile::can_take_tasks(&1));
+		assert_eq!(Profile::penalize(&1, PenaltyReason::AbandonedTask).ok(), Some(1));
+		assert!(!Profile::can_take_tasks(&1));
+		assert_eq!(Profile::penalize(&1, PenaltyReason::AbandonedTask).ok(), Some(0));
+		System::assert_last_event(Event::Profile(crate::Event::ReputationPenalized { who: 1, reason: PenaltyReason::AbandonedTask, penalty: 0 }));
+		assert_eq!(Profile::profiles(1).expect("should find the profile").reputation, 2);
+
+		// Ensure penalties are kept when the profile is deleted and created again
+		assert_ok!(Profile::remove_profile(Origin::signed(1)));
+		assert_eq!(Profile::retained_reputation(1), Some(2));
+		assert_ok!(Profile::create_profile(Origin::signed(1), Vec::new(), Vec::new()));
+		assert_eq!(Profile::reputation_of(&1), 2);
+		assert!(!Profile::can_take_tasks(&1));
+		assert_eq!(Profile::retained_reputation(1), None);
+
+		// Ensure decrements below the floor never underflow
+		let mut profile = Profile::profiles(1).expect("should find the profile");
+		assert_eq!(profile.decrease_reputation(u32::MAX), 0);
+		ReputationFloor::set(0);
+		assert_eq!(profile.decrease_reputation(u32::MAX), 2);
+		assert_eq!(profile.reputation, 0);
+	});
+}
+
+#[test]
+fn skills_are_declared_from_the_registry() {
+	new_test_ext().execute_with(|| {
+		let rust = b"rust".to_vec();
+		assert_noop!(Profile::set_skills(Origin::signed(1), vec![(rust.clone(), 3)]), Error::<Test>::NoProfileCreated);
+		assert_ok!(Profile::create_profile(Origin::signed(1), Vec::new(), Vec::new()));
+
+		// Ensure skills are registered, bounded, unique and have a valid level
+		assert_noop!(Profile::set_skills(Origin::signed(1), vec![(vec![0, 1], 3)]), Error::<Test>::SkillNotRegistered);
+		assert_noop!(Profile::set_skills(Origin::signed(1), vec![(vec![1; 9], 3)]), Error::<Test>::SkillTooLong);
+		assert_noop!(Profile::set_skills(Origin::signed(1), vec![(rust.clone(), 0)]), Error::<Test>::InvalidSkillLevel);
+		assert_noop!(Profile::set_skills(Origin::signed(1), vec![(rust.clone(), 6)]), Error::<Test>::InvalidSkillLevel);
+		assert_noop!(Profile::set_skills(Origin::signed(1), vec![(rust.clone(), 3), (rust.clone(), 4)]), Error::<Test>::DuplicateSkill);
+		assert_noop!(Profile::set_skills(Origin::signed(1), vec![(vec![1], 1), (vec![2], 1), (vec![3], 1), (vec![4], 1)]), Error::<Test>::TooManySkills);
+
+		assert_ok!(Profile::set_skills(Origin::signed(1), vec![(rust.clone(), 4), (b"ink".to_vec(), 2)]));
+		System::assert_last_event(Event::Profile(crate::Event::SkillsUpdated { who: 1 }));
+		let skills = Profile::skills(1);
+		assert_eq!(skills.len(), 2);
+		assert_eq!((skills[0].0.to_vec(), skills[0].1), (rust, 4));
+
+		// Ensure skills are removed with the profile
+		assert_ok!(Profile::remove_profile(Origin::signed(1)));
+		assert!(Profile::skills(1).is_empty());
+	});
+}
+
+#[test]
+fn endorsements_stake_and_rank_skills() {
+	new_test_ext().execute_with(|| {
+		let rust = b"rust".to_vec();
+		for account in 1..=3 {
+			assert_ok!(Profile::create_profile(Origin::signed(account), Vec::new(), Vec::new()));
+			assert_ok!(Profile::set_skills(Origin::signed(account), vec![(rust.clone(), 3)]));
+		}
+
+		// Ensure only declared skills of others are endorsed, with enough stake, once
+		assert_noop!(Profile::endorse_skill(Origin::signed(1), 1, rust.clone(), StakeKind::Deposit), Error::<Test>::CannotEndorseSelf);
+		assert_noop!(Profile::endorse_skill(Origin::signed(2), 1, b"ink".to_vec(), StakeKind::Deposit), Error::<Test>::SkillNotDeclared);
+		assert_noop!(Profile::endorse_skill(Origin::signed(2), 1, rust.clone(), StakeKind::Reputation), Error::<Test>::InsufficientReputation);
+		assert_noop!(Profile::endorse_skill(Origin::signed(3), 1, rust.clone(), StakeKind::Deposit), Error::<Test>::InsufficientBalance);
+		assert_ok!(Profile::endorse_skill(Origin::signed(2), 1, rust.clone(), StakeKind::Deposit));
+		System::assert_last_event(Event::Profile(crate::Event::SkillEndorsed { endorser: 2, who: 1, skill: rust.clone() }));
+		assert_eq!(Balances::reserved_balance(&2), 5);
+		assert_noop!(Profile::endorse_skill(Origin::signed(2), 1, rust.clone(), StakeKind::Deposit), Error::<Test>::AlreadyEndorsed);
+
+		assert_ok!(Profile::add_reputation(&3));
+		assert_ok!(Profile::add_reputation(&3));
+		assert_ok!(Profile::endorse_skill(Origin::signed(3), 1, rust.clone(), StakeKind::Reputation));
+		assert_eq!(Profile::reputation_of(&3), 0);
+		assert_ok!(Profile::endorse_skill(Origin::signed(1), 2, rust.clone(), StakeKind::Deposit));
+
+		// Ensure accounts are ranked by their endorsements for the skill
+		assert_eq!(Profile::endorsements_of(&1, &rust), 2);
+		assert_eq!(Profile::endorsed_accounts(&rust), vec![(1, 2), (2, 1)]);
+
+		// Ensure revoking returns the stake and updates the index
+		assert_ok!(Profile::revoke_endorsement(Origin::signed(3), 1, rust.clone()));
+		System::assert_last_event(Event::Profile(crate::Event::EndorsementRevoked { endorser: 3, who: 1, skill: rust.clone() }));
+		assert_eq!(Profile::reputation_of(&3), 2);
+		assert_noop!(Profile::revoke_endorsement(Origin::signed(3), 1, rust.clone()), Error::<Test>::NotEndorsed);
+		assert_ok!(Profile::revoke_endorsement(Origin::signed(2), 1, rust.clone()));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(Profile::endorsements_of(&1, &rust), 0);
+		assert_eq!(Profile::endorsed_accounts(&rust), vec![(2, 1)]);
+	});
+}
+
+#[test]
+fn removed_skills_return_their_endorsements() {
+	new_test_ext().execute_with(|| {
+		let (rust, ink) = (b"rust".to_vec(), b"ink".to_vec());
+		for account in 1..=5 {
+			assert_ok!(Profile::create_profile(Origin::signed(account), Vec::new(), Vec::new()));
+		}
+		assert_ok!(Profile::set_skills(Origin::signed(1), vec![(rust.clone(), 3), (ink.clone(), 2)]));
+
+		// Ensure a skill can't be endorsed more than allowed
+		assert_ok!(Profile::endorse_skill(Origin::signed(2), 1, rust.clone(), StakeKind::Deposit));
+		for account in 3..=5 {
+			assert_ok!(Profile::add_reputation(&account));
+			assert_ok!(Profile::add_reputation(&account));
+		}
+		assert_ok!(Profile::endorse_skill(Origin::signed(3), 1, rust.clone(), StakeKind::Reputation));
+		assert_ok!(Profile::endorse_skill(Origin::signed(4), 1, rust.clone(), StakeKind::Reputation));
+		assert_noop!(Profile::endorse_skill(Origin::signed(5), 1, rust.clone(), StakeKind::Reputation), Error::<Test>::TooManyEndorsements);
+
+		// Ensure dropping a skill returns the stakes of its endorsements
+		assert_ok!(Profile::set_skills(Origin::signed(1), vec![(ink.clone(), 2)]));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(Profile::reputation_of(&3), 2);
+		assert_eq!(Profile::endorsements_of(&1, &rust), 0);
+		assert_eq!(Profile::endorsed_accounts(&rust), Vec::new());
+
+		// Ensure removing the profile returns the stakes of the endorsements of its skills
+		assert_ok!(Profile::endorse_skill(Origin::signed(2), 1, ink.clone(), StakeKind::Deposit));
+		assert_ok!(Profile::remove_profile(Origin::signed(1)));
+		System::assert_has_event(Event::Profile(crate::Event::EndorsementRevoked { endorser: 2, who: 1, skill: ink.clone() }));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(Profile::endorsements_of(&1, &ink), 0);
+		assert_eq!(Profile::skills(1).len(), 0);
+	});
+}
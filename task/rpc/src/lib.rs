@@ -0,0 +1,108 @@
+// This file is part of Substrate.
+
+// Copyright UNIVERSALDOT FOUNDATION
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! RPC interface for the task pallet.
+//!
+//! Exposes the `TaskApi` runtime API over JSON-RPC. Nodes register it with
+//! `io.extend_with(TaskRpcApi::to_delegate(TaskRpc::new(client.clone())))`.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use pallet_task_runtime_api::TaskApi as TaskRuntimeApi;
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+#[rpc(server)]
+pub trait TaskRpcApi<BlockHash, AccountId, Hash, Task, TaskStatus> {
+	/// Tasks currently owned by the given account.
+	#[rpc(name = "task_tasksByOwner")]
+	fn tasks_by_owner(&self, owner: AccountId, page: u32, page_size: u32, at: Option<BlockHash>) -> Result<Vec<(Hash, Task)>>;
+
+	/// Tasks in the given status.
+	#[rpc(name = "task_tasksByStatus")]
+	fn tasks_by_status(&self, status: TaskStatus, page: u32, page_size: u32, at: Option<BlockHash>) -> Result<Vec<(Hash, Task)>>;
+
+	/// Tasks created by the given account.
+	#[rpc(name = "task_tasksByInitiator")]
+	fn tasks_by_initiator(&self, initiator: AccountId, page: u32, page_size: u32, at: Option<BlockHash>) -> Result<Vec<(Hash, Task)>>;
+
+	/// The task with the given id, if it exists.
+	#[rpc(name = "task_taskDetails")]
+	fn task_details(&self, task_id: Hash, at: Option<BlockHash>) -> Result<Option<Task>>;
+}
+
+/// Implements the `TaskRpcApi` on top of a client providing the `TaskApi` runtime API.
+pub struct TaskRpc<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> TaskRpc<C, Block> {
+	/// Create a new `TaskRpc` for the given client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i64 = 1;
+
+// Maps a failed runtime API call to an RPC error
+fn runtime_error(error: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(RUNTIME_ERROR),
+		message: "Unable to query tasks.".into(),
+		data: Some(format!("{:?}", error).into()),
+	}
+}
+
+impl<C, Block, AccountId, Hash, Task, TaskStatus> TaskRpcApi<<Block as BlockT>::Hash, AccountId, Hash, Task, TaskStatus> for TaskRpc<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: TaskRuntimeApi<Block, AccountId, Hash, Task, TaskStatus>,
+	AccountId: Codec + DeserializeOwned,
+	Hash: Codec + Serialize + DeserializeOwned,
+	Task: Codec + Serialize,
+	TaskStatus: Codec + DeserializeOwned,
+{
+	fn tasks_by_owner(&self, owner: AccountId, page: u32, page_size: u32, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<(Hash, Task)>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client.runtime_api().tasks_by_owner(&at, owner, page, page_size).map_err(runtime_error)
+	}
+
+	fn tasks_by_status(&self, status: TaskStatus, page: u32, page_size: u32, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<(Hash, Task)>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client.runtime_api().tasks_by_status(&at, status, page, page_size).map_err(runtime_error)
+	}
+
+	fn tasks_by_initiator(&self, initiator: AccountId, page: u32, page_size: u32, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<(Hash, Task)>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client.runtime_api().tasks_by_initiator(&at, initiator, page, page_size).map_err(runtime_error)
+	}
+
+	fn task_details(&self, task_id: Hash, at: Option<<Block as BlockT>::Hash>) -> Result<Option<Task>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client.runtime_api().task_details(&at, task_id).map_err(runtime_error)
+	}
+}
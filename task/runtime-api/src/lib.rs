@@ -0,0 +1,63 @@
+// This file is part of Substrate.
+
+// Copyright UNIVERSALDOT FOUNDATION
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the task pallet.
+//!
+//! Runtimes implement the API by forwarding to the pallet, e.g.
+//! `fn tasks_by_tag(tag: Vec<u8>) -> Vec<Hash> { Task::tasks_by_tag(tag) }`,
+//! with `Task<Runtime>` and `TaskStatus` of `pallet-task` as the task and status types.
+//!
+//! Listings are paginated: `page` starts at 0 and holds at most `page_size` tasks.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait TaskApi<AccountId, Hash, Task, TaskStatus> where
+		AccountId: Codec,
+		Hash: Codec,
+		Task: Codec,
+		TaskStatus: Codec,
+	{
+		/// Open tasks that are tagged with the given skill.
+		fn tasks_by_tag(tag: Vec<u8>) -> Vec<Hash>;
+
+		/// Direct subtasks of the given task.
+		fn subtasks(parent: Hash) -> Vec<Hash>;
+
+		/// Every task the given task depends on, each with its direct prerequisites.
+		/// The given task is listed first. Empty for unknown tasks.
+		fn dependency_tree(task_id: Hash) -> Vec<(Hash, Vec<Hash>)>;
+
+		/// Tasks currently owned by the given account.
+		fn tasks_by_owner(owner: AccountId, page: u32, page_size: u32) -> Vec<(Hash, Task)>;
+
+		/// Tasks in the given status.
+		fn tasks_by_status(status: TaskStatus, page: u32, page_size: u32) -> Vec<(Hash, Task)>;
+
+		/// Tasks created by the given account.
+		fn tasks_by_initiator(initiator: AccountId, page: u32, page_size: u32) -> Vec<(Hash, Task)>;
+
+		/// The task with the given id, if it exists.
+		fn task_details(task_id: Hash) -> Option<Task>;
+
+		/// Applicants of the given task with their endorsements for its tags, most endorsed first.
+		fn ranked_applicants(task_id: Hash) -> Vec<(AccountId, u32)>;
+	}
+}
@@ -21,10 +21,11 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as PalletTask;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller, vec, Vec};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller, vec, Vec};
 use frame_system::RawOrigin;
-use frame_support::traits::{Currency};
+use frame_support::{sp_runtime::Percent, traits::{Currency, EnsureOrigin}, BoundedVec};
 use pallet_profile::Pallet as PalletProfile;
+use sp_std::convert::TryInto;
 
 // Helper function to assert event thrown during verification
 fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
@@ -43,35 +44,46 @@ fn create_task_info<T: Config>(_num_fields: u32) -> Task<T> {
 	let volunteer: T::AccountId = whitelisted_caller();
 	let owner: T::AccountId = whitelisted_caller();
 	let balance = <T as pallet::Config>::Currency::total_balance(&initiator);
-	let deadline = u32::MAX;
+	let deadline: T::BlockNumber = u32::MAX.into();
 	let status: TaskStatus = TaskStatus::InProgress;
 
 	// Create object
 	let info = Task {
-		title: data.clone(),
-		specification: data.clone(),
+		title: data.clone().try_into().unwrap(),
+		specification: data.clone().try_into().unwrap(),
 		initiator: initiator,
 		volunteer: volunteer,
 		current_owner: owner,
 		status: status,
 		budget: balance,
 		deadline: deadline,
+		tags: BoundedVec::default(),
 	};
 
 	return info
 }
 
 // Helper function to create a profile
-fn create_profile<T: Config>(){
+fn create_profile<T: Config>(caller: T::AccountId){
 
 	let username = Vec::new();
 	let interests = Vec::new();
 
-	let caller: T::AccountId = whitelisted_caller();
 	let _profile = PalletProfile::<T>::create_profile(RawOrigin::Signed(caller).into(), username, interests);
 
 }
 
+// Helper function to split the budget between the volunteer and other team members
+fn create_team<T: Config>(volunteer: T::AccountId, size: u32) -> Vec<(T::AccountId, Percent)> {
+
+	let share = 100 / size as u8;
+	let mut team = vec![(volunteer, Percent::from_percent(100 - share * (size as u8 - 1)))];
+	for i in 1 .. size {
+		team.push((account("member", i, 0), Percent::from_percent(share)));
+	}
+
+	team
+}
 
 benchmarks! {
 	benchmark_name {
@@ -93,14 +105,57 @@ benchmarks! {
 		let title = vec![0u8, s as u8];
 		let specification = vec![0u8, s as u8];
 		let budget = <T as pallet::Config>::Currency::total_balance(&caller);
+		let deadline = frame_system::Pallet::<T>::block_number() + x.into();
+
+		// Register the maximum amount of tags for the task
+		let mut tags = Vec::new();
+		for i in 0 .. T::MaxTagsPerTask::get() {
+			let tag = vec![i as u8; T::MaxTagLength::get() as usize];
+			let _ = PalletTask::<T>::register_tag(T::TagOrigin::successful_origin(), tag.clone());
+			tags.push(tag);
+		}
 
 		// Create profile before creating a task
-		create_profile::<T>();
+		create_profile::<T>(caller.clone());
 		create_task_info::<T>(1);
 		
 	}: 
 	/* the code to be benchmarked */
-	create_task(RawOrigin::Signed(caller.clone()), title, specification, budget, x)
+	create_task(RawOrigin::Signed(caller.clone()), title, specification, budget, deadline, tags)
+	
+	verify {
+		/* verifying final state */
+		let caller: T::AccountId = whitelisted_caller();
+		let hash = PalletTask::<T>::tasks_owned(&caller)[0];
+
+		assert_last_event::<T>(Event::<T>::TaskCreated(caller, hash).into());
+	}
+
+	create_task_with_asset {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+
+		// Populate data fields
+		let s in 1 .. u8::MAX.into(); // max bytes for specification
+		let title = vec![0u8, s as u8];
+		let specification = vec![0u8, s as u8];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller);
+		let deadline = frame_system::Pallet::<T>::block_number() + 2000u32.into();
+
+		// Register the maximum amount of tags for the task
+		let mut tags = Vec::new();
+		for i in 0 .. T::MaxTagsPerTask::get() {
+			let tag = vec![i as u8; T::MaxTagLength::get() as usize];
+			let _ = PalletTask::<T>::register_tag(T::TagOrigin::successful_origin(), tag.clone());
+			tags.push(tag);
+		}
+
+		// Create profile before creating a task
+		create_profile::<T>(caller.clone());
+		
+	}: 
+	/* the code to be benchmarked */
+	create_task_with_asset(RawOrigin::Signed(caller.clone()), T::AssetId::default(), title, specification, budget, deadline, tags)
 	
 	verify {
 		/* verifying final state */
@@ -110,10 +165,144 @@ benchmarks! {
 		assert_last_event::<T>(Event::<T>::TaskCreated(caller, hash).into());
 	}
 
-	start_task {
+	update_task {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+
+		// Populate data fields
+		let s in 1 .. u8::MAX.into(); // max bytes for specification
+		let x in 1 .. 2000;
+		let title = vec![0u8, s as u8];
+		let specification = vec![0u8, s as u8];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller);
+		let deadline = frame_system::Pallet::<T>::block_number() + x.into();
+
+		// Create profile and a task with a full version history
+		create_profile::<T>(caller.clone());
+		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller.clone()).into(), title.clone(), specification.clone(), T::MinTaskBudget::get(), deadline, Vec::new());
+		let hash_task = PalletTask::<T>::tasks_owned(&caller)[0];
+		for _ in 1 .. T::MaxTaskVersions::get() {
+			let _ = PalletTask::<T>::update_task(RawOrigin::Signed(caller.clone()).into(), hash_task, title.clone(), specification.clone(), T::MinTaskBudget::get(), deadline);
+		}
+
+	}: update_task(RawOrigin::Signed(caller.clone()), hash_task, title, specification, budget, deadline + 1u32.into())
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TaskUpdated(caller, hash_task).into());
+	}
+
+	set_dependencies {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+
+		// Populate data fields
+		let p in 0 .. T::MaxPrerequisites::get();
+		let deadline = frame_system::Pallet::<T>::block_number() + 2000u32.into();
+
+		// Create profile, the task, its parent and prerequisites
+		create_profile::<T>(caller.clone());
+		for _ in 0 .. p + 2 {
+			let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller.clone()).into(), vec![0u8], vec![0u8], T::MinTaskBudget::get(), deadline, Vec::new());
+		}
+		let owned = PalletTask::<T>::tasks_owned(&caller);
+		let hash_task = owned[0];
+		let parent = Some(owned[1]);
+		let prerequisites: Vec<T::Hash> = owned.iter().skip(2).cloned().collect();
+
+	}: set_dependencies(RawOrigin::Signed(caller.clone()), hash_task, parent, prerequisites)
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::DependenciesSet(caller, hash_task).into());
+	}
+
+	register_tag {
+		/* setup initial state */
+		let tag = vec![u8::MAX; T::MaxTagLength::get() as usize];
+		let origin = T::TagOrigin::successful_origin();
+
+	}: _<T::Origin>(origin, tag.clone())
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TagRegistered(tag).into());
+	}
+
+	unregister_tag {
+		/* setup initial state */
+		let tag = vec![u8::MAX; T::MaxTagLength::get() as usize];
+		let origin = T::TagOrigin::successful_origin();
+		let _ = PalletTask::<T>::register_tag(origin.clone(), tag.clone());
+
+	}: _<T::Origin>(origin, tag.clone())
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TagUnregistered(tag).into());
+	}
+
+	apply_for_task {
+		/* setup initial state */
+		let caller_create: T::AccountId = whitelisted_caller();
+		let caller_apply: T::AccountId = account("volunteer", 0, 0);
+
+		// Populate data fields
+		let s in 1 .. u8::MAX.into(); // max bytes for specification
+		let x in 1 .. 2000; 
+		let title = vec![0u8, s as u8];
+		let specification = vec![0u8, s as u8];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller_create);
+		let deadline = frame_system::Pallet::<T>::block_number() + x.into();
+
+		// Create profile before creating a task
+		create_profile::<T>(caller_create.clone());
+		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), title, specification, budget, deadline, Vec::new());
+		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
+		
+	}: apply_for_task(RawOrigin::Signed(caller_apply.clone()), hash_task, Some(budget), hash_task)
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TaskApplied(caller_apply, hash_task).into());
+	}
+
+	withdraw_application {
+		/* setup initial state */
+		let caller_create: T::AccountId = whitelisted_caller();
+		let caller_apply: T::AccountId = account("volunteer", 0, 0);
+
+		// Populate data fields
+		let s in 1 .. u8::MAX.into(); // max bytes for specification
+		let x in 1 .. 2000; 
+		let title = vec![0u8, s as u8];
+		let specification = vec![0u8, s as u8];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller_create);
+		let deadline = frame_system::Pallet::<T>::block_number() + x.into();
+
+		// Create profile before creating a task
+		create_profile::<T>(caller_create.clone());
+		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), title, specification, budget, deadline, Vec::new());
+		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
+		let _ = PalletTask::<T>::apply_for_task(RawOrigin::Signed(caller_apply.clone()).into(), hash_task, None, hash_task);
+		
+	}: withdraw_application(RawOrigin::Signed(caller_apply.clone()), hash_task)
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::ApplicationWithdrawn(caller_apply, hash_task).into());
+	}
+
+	assign_to_applicant {
 		/* setup initial state */
 		let caller_create: T::AccountId = whitelisted_caller();
-		let caller_start: T::AccountId = whitelisted_caller();
+		let caller_start: T::AccountId = account("volunteer", 0, 0);
 
 		// Populate data fields
 		let s in 1 .. u8::MAX.into(); // max bytes for specification
@@ -121,13 +310,15 @@ benchmarks! {
 		let title = vec![0u8, s as u8];
 		let specification = vec![0u8, s as u8];
 		let budget = <T as pallet::Config>::Currency::total_balance(&caller_create);
+		let deadline = frame_system::Pallet::<T>::block_number() + x.into();
 
 		// Create profile before creating a task
-		create_profile::<T>();		
-		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), title, specification, budget, x.into());
+		create_profile::<T>(caller_create.clone());
+		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), title, specification, budget, deadline, Vec::new());
 		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
+		let _ = PalletTask::<T>::apply_for_task(RawOrigin::Signed(caller_start.clone()).into(), hash_task, None, hash_task);
 		
-	}: start_task(RawOrigin::Signed(caller_start.clone()), hash_task)
+	}: assign_to_applicant(RawOrigin::Signed(caller_create.clone()), hash_task, caller_start.clone())
 		/* the code to be benchmarked */
 	
 	verify {
@@ -135,10 +326,192 @@ benchmarks! {
 		assert_last_event::<T>(Event::<T>::TaskAssigned(caller_start, hash_task).into());
 	}
 
+	propose_team {
+		/* setup initial state */
+		let caller_create: T::AccountId = whitelisted_caller();
+		let caller_complete: T::AccountId = account("volunteer", 0, 0);
+
+		// Populate data fields
+		let m in 1 .. T::MaxTeamSize::get();
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller_create);
+		let deadline = frame_system::Pallet::<T>::block_number() + 2000u32.into();
+		let team = create_team::<T>(caller_complete.clone(), m);
+
+		// Create profiles before creating and starting a task
+		create_profile::<T>(caller_create.clone());
+		create_profile::<T>(account("volunteer", 0, 0));
+		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), vec![0u8], vec![0u8], budget, deadline, Vec::new());
+		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
+		let _ = PalletTask::<T>::apply_for_task(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, None, hash_task);
+		let _ = PalletTask::<T>::assign_to_applicant(RawOrigin::Signed(caller_create.clone()).into(), hash_task, caller_complete.clone());
+
+	}: propose_team(RawOrigin::Signed(caller_complete.clone()), hash_task, team)
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TeamProposed(caller_complete, hash_task).into());
+	}
+
+	approve_team {
+		/* setup initial state */
+		let caller_create: T::AccountId = whitelisted_caller();
+		let caller_complete: T::AccountId = account("volunteer", 0, 0);
+
+		// Populate data fields
+		let m in 1 .. T::MaxTeamSize::get();
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller_create);
+		let deadline = frame_system::Pallet::<T>::block_number() + 2000u32.into();
+
+		// Create profiles before creating and starting a task, then replace a full team
+		create_profile::<T>(caller_create.clone());
+		create_profile::<T>(account("volunteer", 0, 0));
+		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), vec![0u8], vec![0u8], budget, deadline, Vec::new());
+		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
+		let _ = PalletTask::<T>::apply_for_task(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, None, hash_task);
+		let _ = PalletTask::<T>::assign_to_applicant(RawOrigin::Signed(caller_create.clone()).into(), hash_task, caller_complete.clone());
+		let _ = PalletTask::<T>::propose_team(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, create_team::<T>(caller_complete.clone(), T::MaxTeamSize::get()));
+		let _ = PalletTask::<T>::approve_team(RawOrigin::Signed(caller_create.clone()).into(), hash_task);
+		let _ = PalletTask::<T>::propose_team(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, create_team::<T>(caller_complete.clone(), m));
+
+	}: approve_team(RawOrigin::Signed(caller_create.clone()), hash_task)
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TeamApproved(caller_create, hash_task).into());
+	}
+
+	fund_task {
+		/* setup initial state */
+		let caller_create: T::AccountId = whitelisted_caller();
+		let caller_fund: T::AccountId = account("funder", 0, 0);
+
+		// Populate data fields
+		let c in 0 .. T::MaxContributors::get() - 1;
+		let amount: <<T as pallet::Config>::Currency as Currency<T::AccountId>>::Balance = 100u32.into();
+		let deadline = frame_system::Pallet::<T>::block_number() + 2000u32.into();
+
+		// Create profile and the task, then let other accounts fund it
+		create_profile::<T>(caller_create.clone());
+		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), vec![0u8], vec![0u8], T::MinTaskBudget::get(), deadline, Vec::new());
+		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
+		for i in 1 ..= c {
+			let contributor: T::AccountId = account("funder", i, 0);
+			<T as pallet::Config>::Currency::make_free_balance_be(&contributor, amount * 10u32.into());
+			let _ = PalletTask::<T>::fund_task(RawOrigin::Signed(contributor).into(), hash_task, amount);
+		}
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller_fund, amount * 10u32.into());
+
+	}: fund_task(RawOrigin::Signed(caller_fund.clone()), hash_task, amount)
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TaskFunded(caller_fund, hash_task, amount).into());
+	}
+
+	abandon_task {
+		/* setup initial state */
+		let caller_create: T::AccountId = whitelisted_caller();
+		let caller_complete: T::AccountId = account("volunteer", 0, 0);
+
+		// Populate data fields
+		let s in 1 .. u8::MAX.into(); // max bytes for specification
+		let x in 1 .. 2000;
+		let title = vec![0u8, s as u8]; 
+		let specification = vec![0u8, s as u8];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller_create);
+		let deadline = frame_system::Pallet::<T>::block_number() + x.into();
+
+		// Create profiles before creating and starting a task
+		create_profile::<T>(caller_create.clone());
+		create_profile::<T>(account("volunteer", 0, 0));
+		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), title, specification, budget, deadline, Vec::new());
+		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
+		let _ = PalletTask::<T>::apply_for_task(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, None, hash_task);
+		let _ = PalletTask::<T>::assign_to_applicant(RawOrigin::Signed(caller_create.clone()).into(), hash_task, caller_complete.clone());
+
+	}: abandon_task(RawOrigin::Signed(caller_complete.clone()), hash_task)
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TaskAbandoned(caller_complete, hash_task).into());
+	}
+
+	unassign_task {
+		/* setup initial state */
+		let caller_create: T::AccountId = whitelisted_caller();
+		let caller_complete: T::AccountId = account("volunteer", 0, 0);
+
+		// Populate data fields
+		let s in 1 .. u8::MAX.into(); // max bytes for specification
+		let x in 1 .. 2000;
+		let title = vec![0u8, s as u8]; 
+		let specification = vec![0u8, s as u8];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller_create);
+		let deadline = frame_system::Pallet::<T>::block_number() + x.into();
+
+		// Create profiles before creating and starting a task
+		create_profile::<T>(caller_create.clone());
+		create_profile::<T>(account("volunteer", 0, 0));
+		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), title, specification, budget, deadline, Vec::new());
+		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
+		let _ = PalletTask::<T>::apply_for_task(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, None, hash_task);
+		let _ = PalletTask::<T>::assign_to_applicant(RawOrigin::Signed(caller_create.clone()).into(), hash_task, caller_complete.clone());
+
+		// Let the grace period pass
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now + T::UnassignGracePeriod::get());
+
+	}: unassign_task(RawOrigin::Signed(caller_create.clone()), hash_task)
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TaskUnassigned(caller_complete, hash_task).into());
+	}
+
+	submit_deliverable {
+		/* setup initial state */
+		let caller_create: T::AccountId = whitelisted_caller();
+		let caller_complete: T::AccountId = account("volunteer", 0, 0);
+
+		// Populate data fields
+		let s in 1 .. u8::MAX.into(); // max bytes for specification
+		let x in 1 .. 2000;
+		let title = vec![0u8, s as u8]; 
+		let specification = vec![0u8, s as u8];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller_create);
+		let deadline = frame_system::Pallet::<T>::block_number() + x.into();
+		let content = vec![u8::MAX; T::MaxDeliverableLength::get() as usize];
+
+		// Create profiles before creating and starting a task
+		create_profile::<T>(caller_create.clone());
+		create_profile::<T>(account("volunteer", 0, 0));
+		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), title, specification, budget, deadline, Vec::new());
+		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
+		let _ = PalletTask::<T>::apply_for_task(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, None, hash_task);
+		let _ = PalletTask::<T>::assign_to_applicant(RawOrigin::Signed(caller_create.clone()).into(), hash_task, caller_complete.clone());
+
+		// Fill the revision history so the oldest revision is dropped
+		for _ in 0 .. T::MaxRevisions::get() {
+			let _ = PalletTask::<T>::submit_deliverable(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, content.clone());
+		}
+
+	}: submit_deliverable(RawOrigin::Signed(caller_complete.clone()), hash_task, content)
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::DeliverableSubmitted(caller_complete, hash_task, T::MaxRevisions::get()).into());
+	}
+
 	complete_task {
 		/* setup initial state */
 		let caller_create: T::AccountId = whitelisted_caller();
-		let caller_complete: T::AccountId = whitelisted_caller();
+		let caller_complete: T::AccountId = account("volunteer", 0, 0);
 
 		// Populate data fields
 		let s in 1 .. u8::MAX.into(); // max bytes for specification
@@ -146,12 +519,16 @@ benchmarks! {
 		let title = vec![0u8, s as u8]; 
 		let specification = vec![0u8, s as u8];
 		let budget = <T as pallet::Config>::Currency::total_balance(&caller_create);
+		let deadline = frame_system::Pallet::<T>::block_number() + x.into();
 
-		// Create profile before creating a task
-		create_profile::<T>();		
-		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), title, specification, budget, x.into());
+		// Create profiles before creating and starting a task
+		create_profile::<T>(caller_create.clone());
+		create_profile::<T>(account("volunteer", 0, 0));
+		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), title, specification, budget, deadline, Vec::new());
 		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
-		let _ = PalletTask::<T>::start_task(RawOrigin::Signed(caller_complete.clone()).into(), hash_task.clone());
+		let _ = PalletTask::<T>::apply_for_task(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, None, hash_task);
+		let _ = PalletTask::<T>::assign_to_applicant(RawOrigin::Signed(caller_create.clone()).into(), hash_task, caller_complete.clone());
+		let _ = PalletTask::<T>::submit_deliverable(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, vec![u8::MAX; T::MaxDeliverableLength::get() as usize]);
 
 	}: complete_task(RawOrigin::Signed(caller_complete.clone()), hash_task)
 		/* the code to be benchmarked */
@@ -161,10 +538,252 @@ benchmarks! {
 		assert_last_event::<T>(Event::<T>::TaskCompleted(caller_complete, hash_task).into());
 	}
 
+	accept_task {
+		/* setup initial state */
+		let caller_create: T::AccountId = whitelisted_caller();
+		let caller_complete: T::AccountId = account("volunteer", 0, 0);
+
+		// Populate data fields
+		let s in 1 .. u8::MAX.into(); // max bytes for specification
+		let x in 1 .. 2000;
+		let title = vec![0u8, s as u8]; 
+		let specification = vec![0u8, s as u8];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller_create);
+		let deadline = frame_system::Pallet::<T>::block_number() + x.into();
+
+		// Create profiles before creating and starting a task
+		create_profile::<T>(caller_create.clone());
+		create_profile::<T>(account("volunteer", 0, 0));
+		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), title, specification, budget, deadline, Vec::new());
+		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
+		let _ = PalletTask::<T>::apply_for_task(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, None, hash_task);
+		let _ = PalletTask::<T>::assign_to_applicant(RawOrigin::Signed(caller_create.clone()).into(), hash_task, caller_complete.clone());
+		let _ = PalletTask::<T>::submit_deliverable(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, vec![u8::MAX; T::MaxDeliverableLength::get() as usize]);
+		let _ = PalletTask::<T>::complete_task(RawOrigin::Signed(caller_complete.clone()).into(), hash_task.clone());
+
+	}: accept_task(RawOrigin::Signed(caller_create.clone()), hash_task, 0)
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TaskAccepted(caller_create, hash_task).into());
+	}
+
+	rate_task {
+		/* setup initial state */
+		let caller_create: T::AccountId = whitelisted_caller();
+		let caller_complete: T::AccountId = account("volunteer", 0, 0);
+
+		// Populate data fields
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller_create);
+		let deadline = frame_system::Pallet::<T>::block_number() + 2000u32.into();
+
+		// Create profiles before creating and closing a task
+		create_profile::<T>(caller_create.clone());
+		create_profile::<T>(account("volunteer", 0, 0));
+		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), vec![0u8], vec![0u8], budget, deadline, Vec::new());
+		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
+		let _ = PalletTask::<T>::apply_for_task(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, None, hash_task);
+		let _ = PalletTask::<T>::assign_to_applicant(RawOrigin::Signed(caller_create.clone()).into(), hash_task, caller_complete.clone());
+		let _ = PalletTask::<T>::submit_deliverable(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, vec![0u8]);
+		let _ = PalletTask::<T>::complete_task(RawOrigin::Signed(caller_complete.clone()).into(), hash_task.clone());
+		let _ = PalletTask::<T>::accept_task(RawOrigin::Signed(caller_create.clone()).into(), hash_task, 0);
+
+	}: rate_task(RawOrigin::Signed(caller_create.clone()), hash_task, 5, hash_task)
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TaskRated(caller_create, hash_task, 5).into());
+	}
+
+	reject_task {
+		/* setup initial state */
+		let caller_create: T::AccountId = whitelisted_caller();
+		let caller_complete: T::AccountId = account("volunteer", 0, 0);
+
+		// Populate data fields
+		let s in 1 .. u8::MAX.into(); // max bytes for specification
+		let x in 1 .. 2000;
+		let title = vec![0u8, s as u8]; 
+		let specification = vec![0u8, s as u8];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller_create);
+		let deadline = frame_system::Pallet::<T>::block_number() + x.into();
+
+		// Create profiles before creating and starting a task
+		create_profile::<T>(caller_create.clone());
+		create_profile::<T>(account("volunteer", 0, 0));
+		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), title, specification, budget, deadline, Vec::new());
+		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
+		let _ = PalletTask::<T>::apply_for_task(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, None, hash_task);
+		let _ = PalletTask::<T>::assign_to_applicant(RawOrigin::Signed(caller_create.clone()).into(), hash_task, caller_complete.clone());
+		let _ = PalletTask::<T>::submit_deliverable(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, vec![u8::MAX; T::MaxDeliverableLength::get() as usize]);
+		let _ = PalletTask::<T>::complete_task(RawOrigin::Signed(caller_complete.clone()).into(), hash_task.clone());
+		let reason = hash_task.clone();
+
+	}: reject_task(RawOrigin::Signed(caller_create.clone()), hash_task, reason)
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TaskRejected(caller_create, hash_task, reason).into());
+	}
+
+	create_task_with_milestones {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+
+		// Populate data fields
+		let s in 1 .. u8::MAX.into(); // max bytes for specification
+		let x in 1 .. 2000;
+		let title = vec![0u8, s as u8];
+		let specification = vec![0u8, s as u8];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller);
+		let deadline = frame_system::Pallet::<T>::block_number() + x.into();
+		let milestones = vec![(T::Hash::default(), Percent::from_percent(50)), (T::Hash::default(), Percent::from_percent(50))];
+
+		// Create profile before creating a task
+		create_profile::<T>(caller.clone());
+		
+	}: 
+	/* the code to be benchmarked */
+	create_task_with_milestones(RawOrigin::Signed(caller.clone()), title, specification, budget, deadline, Vec::new(), milestones)
+	
+	verify {
+		/* verifying final state */
+		let caller: T::AccountId = whitelisted_caller();
+		let hash = PalletTask::<T>::tasks_owned(&caller)[0];
+
+		assert_last_event::<T>(Event::<T>::TaskCreated(caller, hash).into());
+	}
+
+	submit_milestone {
+		/* setup initial state */
+		let caller_create: T::AccountId = whitelisted_caller();
+		let caller_complete: T::AccountId = account("volunteer", 0, 0);
+
+		// Populate data fields
+		let s in 1 .. u8::MAX.into(); // max bytes for specification
+		let x in 1 .. 2000;
+		let title = vec![0u8, s as u8]; 
+		let specification = vec![0u8, s as u8];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller_create);
+		let deadline = frame_system::Pallet::<T>::block_number() + x.into();
+		let milestones = vec![(T::Hash::default(), Percent::from_percent(100))];
+
+		// Create profiles before creating and starting a task
+		create_profile::<T>(caller_create.clone());
+		create_profile::<T>(account("volunteer", 0, 0));
+		let _ = PalletTask::<T>::create_task_with_milestones(RawOrigin::Signed(caller_create.clone()).into(), title, specification, budget, deadline, Vec::new(), milestones);
+		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
+		let _ = PalletTask::<T>::apply_for_task(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, None, hash_task);
+		let _ = PalletTask::<T>::assign_to_applicant(RawOrigin::Signed(caller_create.clone()).into(), hash_task, caller_complete.clone());
+
+	}: submit_milestone(RawOrigin::Signed(caller_complete.clone()), hash_task, 0)
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::MilestoneSubmitted(caller_complete, hash_task, 0).into());
+	}
+
+	approve_milestone {
+		/* setup initial state */
+		let caller_create: T::AccountId = whitelisted_caller();
+		let caller_complete: T::AccountId = account("volunteer", 0, 0);
+
+		// Populate data fields
+		let s in 1 .. u8::MAX.into(); // max bytes for specification
+		let x in 1 .. 2000;
+		let title = vec![0u8, s as u8]; 
+		let specification = vec![0u8, s as u8];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller_create);
+		let deadline = frame_system::Pallet::<T>::block_number() + x.into();
+		let milestones = vec![(T::Hash::default(), Percent::from_percent(100))];
+
+		// Create profiles before creating and starting a task
+		create_profile::<T>(caller_create.clone());
+		create_profile::<T>(account("volunteer", 0, 0));
+		let _ = PalletTask::<T>::create_task_with_milestones(RawOrigin::Signed(caller_create.clone()).into(), title, specification, budget, deadline, Vec::new(), milestones);
+		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
+		let _ = PalletTask::<T>::apply_for_task(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, None, hash_task);
+		let _ = PalletTask::<T>::assign_to_applicant(RawOrigin::Signed(caller_create.clone()).into(), hash_task, caller_complete.clone());
+		let _ = PalletTask::<T>::submit_milestone(RawOrigin::Signed(caller_complete.clone()).into(), hash_task, 0);
+
+	}: approve_milestone(RawOrigin::Signed(caller_create.clone()), hash_task, 0)
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TaskAccepted(caller_create, hash_task).into());
+	}
+
+	raise_dispute {
+		/* setup initial state */
+		let caller_create: T::AccountId = whitelisted_caller();
+		let caller_dispute: T::AccountId = account("volunteer", 0, 0);
+
+		// Populate data fields
+		let s in 1 .. u8::MAX.into(); // max bytes for specification
+		let x in 1 .. 2000;
+		let title = vec![0u8, s as u8]; 
+		let specification = vec![0u8, s as u8];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller_create);
+		let deadline = frame_system::Pallet::<T>::block_number() + x.into();
+
+		// Create profiles before creating and starting a task
+		create_profile::<T>(caller_create.clone());
+		create_profile::<T>(account("volunteer", 0, 0));
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller_dispute, T::DisputeDeposit::get() * 10u32.into());
+		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), title, specification, budget, deadline, Vec::new());
+		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
+		let _ = PalletTask::<T>::apply_for_task(RawOrigin::Signed(caller_dispute.clone()).into(), hash_task, None, hash_task);
+		let _ = PalletTask::<T>::assign_to_applicant(RawOrigin::Signed(caller_create.clone()).into(), hash_task, caller_dispute.clone());
+
+	}: raise_dispute(RawOrigin::Signed(caller_dispute.clone()), hash_task)
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::DisputeRaised(caller_dispute, hash_task).into());
+	}
+
+	vote_on_dispute {
+		/* setup initial state */
+		let caller_create: T::AccountId = whitelisted_caller();
+		let caller_dispute: T::AccountId = account("volunteer", 0, 0);
+		let origin = T::ArbitratorOrigin::successful_origin();
+
+		// Populate data fields
+		let s in 1 .. u8::MAX.into(); // max bytes for specification
+		let x in 1 .. 2000;
+		let title = vec![0u8, s as u8]; 
+		let specification = vec![0u8, s as u8];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller_create);
+		let deadline = frame_system::Pallet::<T>::block_number() + x.into();
+
+		// Create profiles before creating, starting and disputing a task
+		create_profile::<T>(caller_create.clone());
+		create_profile::<T>(account("volunteer", 0, 0));
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller_dispute, T::DisputeDeposit::get() * 10u32.into());
+		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), title, specification, budget, deadline, Vec::new());
+		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
+		let _ = PalletTask::<T>::apply_for_task(RawOrigin::Signed(caller_dispute.clone()).into(), hash_task, None, hash_task);
+		let _ = PalletTask::<T>::assign_to_applicant(RawOrigin::Signed(caller_create.clone()).into(), hash_task, caller_dispute.clone());
+		let _ = PalletTask::<T>::raise_dispute(RawOrigin::Signed(caller_dispute.clone()).into(), hash_task);
+
+	}: _<T::Origin>(origin, hash_task, Percent::from_percent(50))
+		/* the code to be benchmarked */
+	
+	verify {
+		/* verifying final state */
+		let voted = PalletTask::<T>::disputes(&hash_task).map_or(true, |dispute| !dispute.votes.is_empty());
+		assert!(voted);
+	}
+
 	remove_task {
 		/* setup initial state */
 		let caller_create: T::AccountId = whitelisted_caller();
-		let caller_complete: T::AccountId = whitelisted_caller();
 
 		// Populate data fields
 		let s in 1 .. u8::MAX.into(); // max bytes for specification
@@ -172,19 +791,19 @@ benchmarks! {
 		let title = vec![0u8, s as u8]; 
 		let specification = vec![0u8, s as u8];
 		let budget = <T as pallet::Config>::Currency::total_balance(&caller_create);
+		let deadline = frame_system::Pallet::<T>::block_number() + x.into();
 
 		// Create profile before creating a task
-		create_profile::<T>();		
-		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), title, specification, budget, x.into());
+		create_profile::<T>(caller_create.clone());
+		let _ = PalletTask::<T>::create_task(RawOrigin::Signed(caller_create.clone()).into(), title, specification, budget, deadline, Vec::new());
 		let hash_task = PalletTask::<T>::tasks_owned(&caller_create)[0];
-		let _ = PalletTask::<T>::start_task(RawOrigin::Signed(caller_complete.clone()).into(), hash_task.clone());
 
-	}: remove_task(RawOrigin::Signed(caller_complete.clone()), hash_task)
+	}: remove_task(RawOrigin::Signed(caller_create.clone()), hash_task)
 		/* the code to be benchmarked */
 	
 	verify {
 		/* verifying final state */
-		assert_last_event::<T>(Event::<T>::TaskRemoved(caller_complete, hash_task).into());
+		assert_last_event::<T>(Event::<T>::TaskRemoved(caller_create, hash_task).into());
 	}
 }
 
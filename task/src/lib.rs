@@ -29,31 +29,160 @@
 //! Initiators and Volunteers. 
 //! 
 //! Initiators are people who have the permission to Create and Remove Tasks.
-//! Volunteers are people who have the permission to Start and Complete Tasks.
+//! Volunteers are people who have the permission to Apply for and Complete Tasks.
 //! 
 //! Anybody can become an Initiator or Volunteer. In other words, 
 //! one doesn't need permission to become an Initiator or Volunteer. 
 //! 
+//! Tasks move through the statuses Created, InProgress, InReview and Closed.
+//! Every status change is validated by the transition table in `TaskStatus::transition`,
+//! which defines who may perform each action in each status.
+//! 
 //! When Tasks are created, there is some associated metadata that shall be defined.
 //! This includes the following:
 //! - Task Specification (Defining the Task specification)
 //! - Task Budget (The cost of completion for the Task)
-//! - Task Deadline (The specified time until which the task should be completed)
+//! - Task Deadline (The block until which the task should be completed)
+//! 
+//! Tasks that are not completed by their deadline block are automatically set to
+//! Expired and their escrowed budget is returned to the initiator. Volunteers of expired
+//! tasks are penalized for missing the deadline. Every task takes one of the bounded deadline
+//! slots of its block, so its budget must be at least `MinTaskBudget`.
 //! 
-//! Furthermore, budget funds are locked in escrow when task is created. 
-//! Funds are removed from escrow when task is removed. 
+//! Furthermore, budget funds are reserved in escrow when task is created. 
+//! The escrow is a reserve named by the task id, so it can only pay out that task and is
+//! kept apart from other reserves of the initiator, such as dispute or endorsement deposits.
+//! When a task is accepted, the escrowed funds are paid to the volunteer.
+//! When an unfinished task is removed, the escrowed funds are returned to the initiator.
 //!
 //! ## Interface
 //!
 //! ### Public Functions
 //!
-//! - `create_task` - Function used to create a new task. 
+//! - `create_task` - Function used to create a new task. Tasks can be tagged with
+//! skills from the tag registry. Titles and specifications are limited to `MaxTitleLength`
+//! and `MaxSpecificationLength` bytes.
+//! 
+//! - `update_task` - Function used by the initiator to correct the title, specification,
+//! budget or deadline of a task that has not been started. The escrow follows the new
+//! budget and a bounded history of versions is kept. Task ids stay the same across edits, since
+//! they are derived from the initiator and a nonce. Tasks created before storage version 1
+//! are re-keyed by a runtime upgrade that escrows their budgets, and tasks stored before
+//! storage version 2 are converted to block number deadlines with oversize titles and
+//! specifications truncated. Their expiry is scheduled, or they expire at once when their
+//! deadline has already passed.
+//! 
+//! - `register_tag` - Function used by governance to add a skill tag to the registry.
+//! 
+//! - `unregister_tag` - Function used by governance to remove a skill tag from the registry.
+//! Tasks that already use the tag keep it.
+//! 
+//! - `set_dependencies` - Function used by the initiator to declare the parent task and the
+//! prerequisites of a task that has not been started. Dependencies form a DAG, so changes
+//! that would introduce a cycle are refused. A task can only be started once all of its
+//! prerequisites are closed. The amount of dependents and subtasks of a task is bounded, so
+//! removing a task detaches it from the DAG at a bounded cost.
+//! 
+//! Open tasks per skill tag, subtasks and the dependency tree of a task can be listed
+//! through the `TaskApi` runtime API of `pallet-task-runtime-api`. It also offers paginated
+//! listings of tasks by owner, status and initiator, and the details of a task, which
+//! `pallet-task-rpc` serves over JSON-RPC. Applicants of a task can be ranked by the
+//! endorsements of their profile skills that match the tags of the task.
+//! 
+//! Profiles draw their skills from the skill tag registry, so runtimes configure this pallet
+//! as the `SkillRegistry` of the profile pallet.
+//! 
+//! - `apply_for_task` - Function used by a volunteer to apply for a task, with an
+//! optional counter-offer price and a pitch.
+//! 
+//! - `withdraw_application` - Function used by a volunteer to withdraw an application.
+//! 
+//! - `assign_to_applicant` - Function used by the initiator to start a task by
+//! assigning it to one of the applicants.
+//! 
+//! - `submit_deliverable` - Function used by the volunteer to record a content identifier,
+//! such as an IPFS CID or a blake2 hash, of the delivered work. A bounded history of
+//! revisions is kept, dropping the oldest revision once full.
+//! 
+//! - `abandon_task` - Function used by the volunteer to back out of a task in progress.
+//! 
+//! - `unassign_task` - Function used by the initiator to take back a task in progress
+//! once the volunteer has been silent for the grace period. Assigning the task, submitting
+//! work and rejected reviews count as activity of the volunteer.
+//! 
+//! Abandoned and unassigned tasks return to Created and the volunteer loses reputation.
+//! Accounts below the minimum reputation of the profile pallet can not apply for, be
+//! assigned or join the team of new tasks.
+//! 
+//! - `propose_team` - Function used by the volunteer to propose a team of volunteers,
+//! each with a percentage share of the budget. The volunteer leads the team and has to be
+//! part of it.
+//! 
+//! - `approve_team` - Function used by the initiator to approve the proposed split. Team
+//! members own the task while it is in progress, and payouts and reputation are divided
+//! between them by share.
+//! 
+//! - `fund_task` - Function used by any account to add to the escrowed budget of a task
+//! that is not yet in review. The whole pool is paid to the volunteer when the task is
+//! accepted, while contributors are refunded if the task is removed or expires.
+//! 
+//! - `complete_task` - Function used by the volunteer to submit a task for review.
+//! At least one deliverable has to be submitted first.
+//! 
+//! - `accept_task` - Function used by the initiator to accept a task in review.
+//! The initiator names the deliverable revision being paid for, which must be the latest.
+//! Accepting pays the volunteer and rewards reputation, as scored by the `ReputationHandler`
+//! of the profile pallet from the budget paid, the DAO and the lateness of the task.
+//! 
+//! - `reject_task` - Function used by the initiator to reject a task in review.
+//! Rejected tasks are returned to the volunteer.
+//! 
+//! - `remove_task` - Function used to remove task. Closed tasks keep a `TaskReceipt` with the
+//! initiator, the volunteer, the amount paid, the closing block and a hash of the final task,
+//! indexed by the accounts involved so work history can be proven after removal.
+//! 
+//! - `create_task_with_milestones` - Function used to create a task whose budget is
+//! split into an ordered list of milestones.
+//! 
+//! - `create_task_with_asset` - Function used to create a task whose budget is paid in an
+//! asset of `Config::Assets` instead of the native currency. The budget and any contributions
+//! are held by the pallet account until they are paid out or refunded in the same asset.
+//! Assets that are not sufficient require the pallet account to exist.
+//! 
+//! - `submit_milestone` - Function used by the volunteer to submit the next milestone.
+//! Submitting the last milestone puts the task in review.
+//! 
+//! - `approve_milestone` - Function used by the initiator to approve a submitted milestone,
+//! which pays its share of the budget. Approving the last milestone closes the task.
+//! 
+//! - `raise_dispute` - Function used by the initiator or volunteer of a started task to
+//! dispute it. A deposit is reserved from the caller and the escrowed budget is frozen.
+//! 
+//! - `vote_on_dispute` - Function used by arbitrators to vote on the share of the remaining
+//! budget that the volunteer receives. Arbitrators are accounts of the configured
+//! `ArbitratorOrigin` or members of the DAO that owns the task.
+//!
+//! ### Disputes
 //! 
-//! - `start_task` - Function used to start already existing task.
+//! A dispute is resolved once `DisputeQuorum` arbitrators have voted, using the median of
+//! the votes. If no ruling is reached within `DisputeTimeout` blocks the votes cast so far
+//! decide, or the budget is split evenly when there are none. A dispute is frivolous when
+//! the ruling gives everything to the other party, in which case the deposit is slashed.
+//! The party the ruling goes against, with less than half of the budget, loses reputation.
+//!
+//! ### Ratings
 //! 
-//! - `complete_task` - Function used to complete a task.
+//! Within `RatingPeriod` blocks after a task is closed, the initiator and the volunteer can
+//! each rate the other once with `rate_task`, giving a score from 1 to 5 and a review hash.
+//! Ratings are stored per task and aggregated on the profile of the rated account, whose
+//! reputation moves with the score.
+//!
+//! ### Commission
 //! 
-//! - `remove_task` - Function used to remove task.
+//! Every payout to a volunteer pays `Commission` to a treasury and the rest to the volunteer.
+//! The treasury is an account derived from `PalletId`, unless `Config::Treasury` names another
+//! one for the task, such as the treasury of the DAO that owns it, which may also override
+//! the commission.
 //!
 //! ## Related Modules
 //!
@@ -75,15 +204,16 @@ mod benchmarking;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use crate::TaskStatus::Created; //TODO: Better import
 	use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
 	use frame_system::pallet_prelude::*;
 	use frame_support::{
-		sp_runtime::traits:: Hash,
-		traits::{Currency, tokens::ExistenceRequirement}, 
-		transactional};
+		sp_runtime::{traits::{AccountIdConversion, Hash, SaturatedConversion, Saturating, Zero}, Percent, Permill},
+		storage::unhashed,
+		traits::{Currency, ExistenceRequirement, NamedReservableCurrency, ReservableCurrency, BalanceStatus, GetStorageVersion, StorageVersion, tokens::fungibles},
+		transactional, PalletId};
+	use pallet_profile::PenaltyReason;
 	use scale_info::TypeInfo;
-	use sp_std::vec::Vec;
+	use sp_std::{convert::{TryFrom, TryInto}, vec::Vec};
 
 	#[cfg(feature = "std")]
 	use frame_support::serde::{Deserialize, Serialize};
@@ -92,46 +222,459 @@ pub mod pallet {
 	type AccountOf<T> = <T as frame_system::Config>::AccountId;
 	type BalanceOf<T> =<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+	/// Storage version 1 keys tasks by `hash(initiator, nonce)` instead of the hash of the task.
+	/// Storage version 2 bounds the title and specification of tasks, stores deadlines as block
+	/// numbers and adds tags.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+	/// Skill tag of a task.
+	pub type TagOf<T> = BoundedVec<u8, <T as Config>::MaxTagLength>;
+
 	// Struct for holding Task information.
-	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	#[cfg_attr(feature = "std", derive(Serialize), serde(bound(serialize = "")))]
 	pub struct Task<T: Config> {
-		pub title: Vec<u8>,
-		pub specification: Vec<u8>,
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		pub specification: BoundedVec<u8, T::MaxSpecificationLength>,
 		pub initiator: AccountOf<T>,
 		pub volunteer: AccountOf<T>,
 		pub current_owner: AccountOf<T>,
 		pub status: TaskStatus,
 		pub budget: BalanceOf<T>,
-		pub deadline: u32,
+		pub deadline: T::BlockNumber,
+		pub tags: BoundedVec<TagOf<T>, T::MaxTagsPerTask>,
+	}
+
+	// Layout of tasks before storage version 2, used by the migration. Deadlines were stored
+	// as `u32` and tasks had no tags.
+	#[derive(Encode, Decode)]
+	struct LegacyTask<T: Config> {
+		title: Vec<u8>,
+		specification: Vec<u8>,
+		initiator: AccountOf<T>,
+		volunteer: AccountOf<T>,
+		current_owner: AccountOf<T>,
+		status: TaskStatus,
+		budget: BalanceOf<T>,
+		deadline: u32,
 	}
 
-	// Set TaskStatus enum.
-	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	// Set TaskStatus enum. New statuses are appended so stored statuses keep their encoding.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	#[scale_info(skip_type_params(T))]
   	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
   	pub enum TaskStatus {
     	Created,
     	InProgress,
 		Closed,
+		InReview,
+		Expired,
+		Disputed,
   	}
 
+	// Struct for holding an application of a volunteer for a task.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Application<T: Config> {
+		pub applicant: AccountOf<T>,
+		pub price: Option<BalanceOf<T>>,
+		pub pitch: T::Hash,
+	}
+
+	// Struct for holding a milestone of a task.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Milestone<T: Config> {
+		pub description: T::Hash,
+		pub share: Percent,
+		pub status: MilestoneStatus,
+	}
+
+	// Set MilestoneStatus enum.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum MilestoneStatus {
+		Pending,
+		Submitted,
+		Approved,
+	}
+
+	// Struct for holding a version of the editable fields of a task.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct TaskVersion<T: Config> {
+		pub updated_at: T::BlockNumber,
+		pub title: T::Hash,
+		pub specification: T::Hash,
+		pub budget: BalanceOf<T>,
+		pub deadline: T::BlockNumber,
+	}
+
+	// Struct for holding a revision of the work delivered for a task.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Deliverable<T: Config> {
+		pub revision: u32,
+		pub content: BoundedVec<u8, T::MaxDeliverableLength>,
+		pub submitted_at: T::BlockNumber,
+	}
+
+	// Struct for holding the permanent record of a closed task.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct TaskReceipt<T: Config> {
+		pub initiator: AccountOf<T>,
+		pub volunteer: AccountOf<T>,
+		pub paid: BalanceOf<T>,
+		pub asset: Option<T::AssetId>,
+		pub closed_at: T::BlockNumber,
+		pub task_hash: T::Hash,
+		pub organization: Option<AccountOf<T>>,
+	}
+
+	// Struct for holding the rating one party of a closed task gave the other.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Rating<T: Config> {
+		pub ratee: AccountOf<T>,
+		pub score: u8,
+		pub review: T::Hash,
+	}
+
+	// Struct for holding a dispute about a task.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Dispute<T: Config> {
+		pub raised_by: AccountOf<T>,
+		pub deliverable: Option<u32>,
+		pub deposit: BalanceOf<T>,
+		pub timeout: T::BlockNumber,
+		pub votes: BoundedVec<(AccountOf<T>, Percent), T::MaxArbitrators>,
+	}
+
+	/// Source of the accounts that can arbitrate a dispute about a task.
+	pub trait TaskArbitrators<AccountId, Hash> {
+		fn arbitrators(task_id: &Hash) -> Vec<AccountId>;
+	}
+
+	impl<AccountId, Hash> TaskArbitrators<AccountId, Hash> for () {
+		fn arbitrators(_task_id: &Hash) -> Vec<AccountId> {
+			Vec::new()
+		}
+	}
+
+	/// Treasury and commission of the owner of a task, such as the DAO it belongs to.
+	pub trait TaskTreasury<AccountId, Hash> {
+		/// Account receiving the commission on payouts of the task, if not the pallet treasury.
+		fn treasury(task_id: &Hash) -> Option<AccountId>;
+
+		/// Commission taken from payouts of the task, if not the default commission.
+		fn commission(task_id: &Hash) -> Option<Permill>;
+	}
+
+	impl<AccountId, Hash> TaskTreasury<AccountId, Hash> for () {
+		fn treasury(_task_id: &Hash) -> Option<AccountId> {
+			None
+		}
+
+		fn commission(_task_id: &Hash) -> Option<Permill> {
+			None
+		}
+	}
+
+	/// Organizations tasks belong to, such as DAOs.
+	pub trait TaskOrganizations<AccountId, Hash> {
+		/// Account of the organization the task belongs to, if any.
+		fn organization(task_id: &Hash) -> Option<AccountId>;
+
+		/// Called when a task is closed, expires or is removed, so it no longer belongs to an organization.
+		fn task_removed(task_id: &Hash);
+	}
+
+	impl<AccountId, Hash> TaskOrganizations<AccountId, Hash> for () {
+		fn organization(_task_id: &Hash) -> Option<AccountId> {
+			None
+		}
+
+		fn task_removed(_task_id: &Hash) {}
+	}
+
+	// Actions that move a task from one status to the next.
+	#[derive(Clone, Copy, PartialEq, RuntimeDebug)]
+	pub enum TaskAction {
+		Start,
+		Complete,
+		Accept,
+		Reject,
+		Remove,
+		Expire,
+		Dispute,
+		Resolve,
+		Update,
+		Abandon,
+		Unassign,
+		Fund,
+		ApproveMilestone,
+	}
+
+	// Role of the account performing an action on a task.
+	#[derive(Clone, Copy, PartialEq, RuntimeDebug)]
+	pub enum TaskRole {
+		Initiator,
+		Volunteer,
+		Other,
+		System,
+	}
+
+	impl TaskStatus {
+		/// Task lifecycle transition table.
+		///
+		/// Returns the status a task moves to when `role` performs `action`, or the error
+		/// describing why the transition is not allowed. Removed tasks keep their status
+		/// since they are deleted from storage.
+		///
+		/// | From       | Action   | Role      | To         |
+		/// |------------|----------|-----------|------------|
+		/// | Created    | Start    | Initiator | InProgress |
+		/// | Created    | Update   | Initiator | Created    |
+		/// | InProgress | Complete | Volunteer | InReview   |
+		/// | InProgress | Abandon  | Volunteer | Created    |
+		/// | InProgress | Unassign | Initiator | Created    |
+		/// | InReview   | Accept   | Initiator | Closed     |
+		/// | InReview   | Reject   | Initiator | InProgress |
+		/// | Created    | Remove   | Initiator | -          |
+		/// | Closed     | Remove   | Initiator | -          |
+		/// | Expired    | Remove   | Initiator | -          |
+		/// | Created    | Expire   | System    | Expired    |
+		/// | InProgress | Expire   | System    | Expired    |
+		/// | InProgress | Dispute  | Initiator | Disputed   |
+		/// | InProgress | Dispute  | Volunteer | Disputed   |
+		/// | InReview   | Dispute  | Initiator | Disputed   |
+		/// | InReview   | Dispute  | Volunteer | Disputed   |
+		/// | Disputed   | Resolve  | System    | Closed     |
+		pub fn transition<T: Config>(&self, action: TaskAction, role: TaskRole) -> Result<TaskStatus, Error<T>> {
+			use TaskStatus::*;
+
+			match (self, action) {
+				(Created, TaskAction::Start) => match role {
+					TaskRole::Initiator => Ok(InProgress),
+					_ => Err(Error::<T>::OnlyInitiatorAssignsTask),
+				},
+				(Created, TaskAction::Update) => match role {
+					TaskRole::Initiator => Ok(Created),
+					_ => Err(Error::<T>::OnlyInitiatorUpdatesTask),
+				},
+				(InProgress, TaskAction::Complete) => match role {
+					TaskRole::Volunteer => Ok(InReview),
+					_ => Err(Error::<T>::NoPermissionToComplete),
+				},
+				(InProgress, TaskAction::Abandon) => match role {
+					TaskRole::Volunteer => Ok(Created),
+					_ => Err(Error::<T>::OnlyVolunteerAbandonsTask),
+				},
+				(InProgress, TaskAction::Unassign) => match role {
+					TaskRole::Initiator => Ok(Created),
+					_ => Err(Error::<T>::OnlyInitiatorUnassignsTask),
+				},
+				(InReview, TaskAction::Accept) => match role {
+					TaskRole::Initiator => Ok(Closed),
+					_ => Err(Error::<T>::OnlyInitiatorReviewsTask),
+				},
+				(InReview, TaskAction::Reject) => match role {
+					TaskRole::Initiator => Ok(InProgress),
+					_ => Err(Error::<T>::OnlyInitiatorReviewsTask),
+				},
+				(Created, TaskAction::Remove) | (Closed, TaskAction::Remove) | (Expired, TaskAction::Remove) => match role {
+					TaskRole::Initiator => Ok(self.clone()),
+					_ => Err(Error::<T>::OnlyInitiatorClosesTask),
+				},
+				(Created, TaskAction::Expire) | (InProgress, TaskAction::Expire) => match role {
+					TaskRole::System => Ok(Expired),
+					_ => Err(Error::<T>::OnlySystemExpiresTask),
+				},
+				(InProgress, TaskAction::Dispute) | (InReview, TaskAction::Dispute) => match role {
+					TaskRole::Initiator | TaskRole::Volunteer => Ok(Disputed),
+					_ => Err(Error::<T>::NotTaskParty),
+				},
+				(Created, TaskAction::Fund) | (InProgress, TaskAction::Fund) => Ok(self.clone()),
+				(InProgress, TaskAction::ApproveMilestone) | (InReview, TaskAction::ApproveMilestone) => match role {
+					TaskRole::Initiator => Ok(self.clone()),
+					_ => Err(Error::<T>::OnlyInitiatorReviewsTask),
+				},
+				(Disputed, TaskAction::Resolve) => match role {
+					TaskRole::System => Ok(Closed),
+					_ => Err(Error::<T>::OnlyArbitratorsResolveDispute),
+				},
+
+				// Illegal transitions
+				(Closed, _) => Err(Error::<T>::TaskAlreadyClosed),
+				(Expired, _) => Err(Error::<T>::TaskAlreadyExpired),
+				(Disputed, _) => Err(Error::<T>::TaskDisputed),
+				(_, TaskAction::Resolve) => Err(Error::<T>::NoDispute),
+				(Created, TaskAction::Dispute) | (Created, TaskAction::Abandon) | (Created, TaskAction::Unassign) => Err(Error::<T>::TaskNotStarted),
+				(InReview, TaskAction::Abandon) | (InReview, TaskAction::Unassign) | (InReview, TaskAction::Fund) => Err(Error::<T>::TaskInReview),
+				(InProgress, TaskAction::Start) | (InReview, TaskAction::Start) |
+				(InProgress, TaskAction::Update) | (InReview, TaskAction::Update) => Err(Error::<T>::TaskAlreadyStarted),
+				(Created, TaskAction::Complete) | (Created, TaskAction::ApproveMilestone) => Err(Error::<T>::TaskNotStarted),
+				(InReview, TaskAction::Complete) => Err(Error::<T>::TaskAlreadySubmitted),
+				(Created, TaskAction::Accept) | (Created, TaskAction::Reject) |
+				(InProgress, TaskAction::Accept) | (InProgress, TaskAction::Reject) => Err(Error::<T>::TaskNotInReview),
+				(InProgress, TaskAction::Remove) => Err(Error::<T>::TaskInProgress),
+				(InReview, TaskAction::Remove) | (InReview, TaskAction::Expire) => Err(Error::<T>::TaskInReview),
+			}
+		}
+	}
+
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_profile::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-		/// Currency type that is linked with AccountID
-		type Currency: Currency<Self::AccountId>;
+		/// Currency type that is linked with AccountID. Task budgets are reserved in escrow under
+		/// the id of their task, so they are kept apart from other reserves of the same account.
+		/// An account can escrow as many tasks at once as the currency allows named reserves.
+		type Currency: NamedReservableCurrency<Self::AccountId, ReserveIdentifier = Self::Hash>;
+
+		/// Identifier of the assets that task budgets can be paid in besides the native currency.
+		type AssetId: Member + Parameter + Default + Copy + MaxEncodedLen;
+
+		/// Fungible assets that task budgets can be paid in. Their escrow is held by the pallet account.
+		type Assets: fungibles::Transfer<Self::AccountId, AssetId = Self::AssetId, Balance = BalanceOf<Self>>;
+
+		/// The task pallet's id, used to derive the account holding asset budgets in escrow.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The maximum length of a task title.
+		#[pallet::constant]
+		type MaxTitleLength: Get<u32>;
+
+		/// The maximum length of a task specification.
+		#[pallet::constant]
+		type MaxSpecificationLength: Get<u32>;
 
 		/// The maximum amount of tasks a single account can own.
 		#[pallet::constant]
 		type MaxTasksOwned: Get<u32>;
+
+		/// The maximum amount of tasks that can expire in a single block.
+		#[pallet::constant]
+		type MaxDeadlinesPerBlock: Get<u32>;
+
+		/// The minimum budget of a task, so deadline slots can not be filled for free.
+		#[pallet::constant]
+		type MinTaskBudget: Get<BalanceOf<Self>>;
+
+		/// The maximum amount of applications a single task can have.
+		#[pallet::constant]
+		type MaxApplicantsPerTask: Get<u32>;
+
+		/// The maximum amount of milestones a single task can have.
+		#[pallet::constant]
+		type MaxMilestones: Get<u32>;
+
+		/// The maximum amount of versions kept for a task.
+		#[pallet::constant]
+		type MaxTaskVersions: Get<u32>;
+
+		/// The maximum length of a deliverable content identifier.
+		#[pallet::constant]
+		type MaxDeliverableLength: Get<u32>;
+
+		/// The maximum amount of deliverable revisions kept for a task.
+		#[pallet::constant]
+		type MaxRevisions: Get<u32>;
+
+		/// The maximum amount of volunteers in a team.
+		#[pallet::constant]
+		type MaxTeamSize: Get<u32>;
+
+		/// The maximum amount of third-party contributors to a task's budget.
+		#[pallet::constant]
+		type MaxContributors: Get<u32>;
+
+		/// The amount of blocks without activity after which the initiator can unassign the volunteer.
+		#[pallet::constant]
+		type UnassignGracePeriod: Get<Self::BlockNumber>;
+
+		/// Origin that manages the skill tag registry.
+		type TagOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum length of a skill tag.
+		#[pallet::constant]
+		type MaxTagLength: Get<u32>;
+
+		/// The maximum amount of skill tags a single task can have.
+		#[pallet::constant]
+		type MaxTagsPerTask: Get<u32>;
+
+		/// The maximum amount of prerequisites a single task can have.
+		#[pallet::constant]
+		type MaxPrerequisites: Get<u32>;
+
+		/// The maximum depth of parent and prerequisite chains that is searched for cycles.
+		#[pallet::constant]
+		type MaxDependencyDepth: Get<u32>;
+
+		/// The maximum amount of tasks that can have a single task as prerequisite.
+		#[pallet::constant]
+		type MaxDependents: Get<u32>;
+
+		/// The maximum amount of subtasks a single task can have.
+		#[pallet::constant]
+		type MaxSubtasks: Get<u32>;
+
+		/// Origin of accounts that can arbitrate any dispute.
+		type ArbitratorOrigin: EnsureOrigin<Self::Origin, Success = Self::AccountId>;
+
+		/// Additional arbitrators of a task, such as the members of the DAO that owns it.
+		type Arbitrators: TaskArbitrators<Self::AccountId, Self::Hash>;
+
+		/// The commission taken from every payout to a volunteer.
+		#[pallet::constant]
+		type Commission: Get<Permill>;
+
+		/// Treasury and commission overrides of a task, such as those of the DAO that owns it.
+		type Treasury: TaskTreasury<Self::AccountId, Self::Hash>;
+
+		/// Organization a task belongs to, which scopes the reputation earned with it.
+		type Organizations: TaskOrganizations<Self::AccountId, Self::Hash>;
+
+		/// The deposit reserved from the account raising a dispute.
+		#[pallet::constant]
+		type DisputeDeposit: Get<BalanceOf<Self>>;
+
+		/// The amount of blocks arbitrators have to reach a ruling.
+		#[pallet::constant]
+		type DisputeTimeout: Get<Self::BlockNumber>;
+
+		/// The amount of votes needed to resolve a dispute.
+		#[pallet::constant]
+		type DisputeQuorum: Get<u32>;
+
+		/// The maximum amount of arbitrators that can vote on a single dispute.
+		#[pallet::constant]
+		type MaxArbitrators: Get<u32>;
+
+		/// The amount of blocks after a task is closed during which its parties can rate each other.
+		#[pallet::constant]
+		type RatingPeriod: Get<Self::BlockNumber>;
 	}
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_storage_info]
 	pub struct Pallet<T>(_);
 
 
@@ -140,6 +683,16 @@ pub mod pallet {
 	/// TaskCount: Get total number of Tasks in the system
 	pub(super) type TaskCount<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn task_nonce)]
+	/// TaskNonce: Number of Tasks ever created, used to derive stable task ids
+	pub(super) type TaskNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn legacy_task_ids)]
+	/// Ids of Tasks created before storage version 1 [key: old hash id, value: new hash id]
+	pub(super) type LegacyTaskIds<T: Config> = StorageMap<_, Twox64Concat, T::Hash, T::Hash>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn tasks)]
 	/// Tasks: Store Tasks in a  Storage Map where [key: hash, value: Task]
@@ -150,6 +703,108 @@ pub mod pallet {
 	/// Keeps track of which Accounts own which Tasks.
 	pub(super) type TasksOwned<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<T::Hash, T::MaxTasksOwned>, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn expiring_tasks)]
+	/// Index of Tasks by deadline, processed when the deadline block is initialized [key: BlockNumber, value: Vec<hash id>]
+	pub(super) type ExpiringTasks<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, BoundedVec<T::Hash, T::MaxDeadlinesPerBlock>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn applications)]
+	/// Applications of volunteers for Tasks that have not been started [key: hash id, value: Vec<Application>]
+	pub(super) type Applications<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BoundedVec<Application<T>, T::MaxApplicantsPerTask>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn milestones)]
+	/// Ordered milestones of Tasks and their progress [key: hash id, value: Vec<Milestone>]
+	pub(super) type Milestones<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BoundedVec<Milestone<T>, T::MaxMilestones>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn proposed_teams)]
+	/// Teams proposed by volunteers awaiting approval [key: hash id, value: Vec<(AccountID, share)>]
+	pub(super) type ProposedTeams<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BoundedVec<(T::AccountId, Percent), T::MaxTeamSize>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn teams)]
+	/// Teams approved by initiators [key: hash id, value: Vec<(AccountID, share)>]
+	pub(super) type Teams<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BoundedVec<(T::AccountId, Percent), T::MaxTeamSize>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn contributions)]
+	/// Budget top-ups held in escrow by their funders [key: hash id, value: Vec<(AccountID, amount)>]
+	pub(super) type Contributions<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BoundedVec<(T::AccountId, BalanceOf<T>), T::MaxContributors>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn task_asset)]
+	/// Asset of Tasks whose budget is not in the native currency [key: hash id, value: AssetId]
+	pub(super) type TaskAssets<T: Config> = StorageMap<_, Twox64Concat, T::Hash, T::AssetId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn last_activity)]
+	/// Last block a volunteer worked on a Task [key: hash id, value: BlockNumber]
+	pub(super) type LastActivity<T: Config> = StorageMap<_, Twox64Concat, T::Hash, T::BlockNumber>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn skill_tags)]
+	/// Registry of skill tags that tasks can use [key: tag, value: ()]
+	pub(super) type SkillTags<T: Config> = StorageMap<_, Blake2_128Concat, TagOf<T>, ()>;
+
+	#[pallet::storage]
+	/// Open Tasks indexed by skill tag [key1: tag, key2: hash id, value: ()]
+	pub(super) type TasksByTag<T: Config> = StorageDoubleMap<_, Blake2_128Concat, TagOf<T>, Twox64Concat, T::Hash, ()>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn prerequisites)]
+	/// Tasks that have to be closed before a Task can start [key: hash id, value: Vec<hash id>]
+	pub(super) type Prerequisites<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BoundedVec<T::Hash, T::MaxPrerequisites>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn dependents)]
+	/// Reverse index of prerequisites [key: prerequisite hash id, value: Vec<dependent hash id>]
+	pub(super) type Dependents<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BoundedVec<T::Hash, T::MaxDependents>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn parent_task)]
+	/// Parent of a subtask [key: hash id, value: parent hash id]
+	pub(super) type ParentTask<T: Config> = StorageMap<_, Twox64Concat, T::Hash, T::Hash>;
+
+	#[pallet::storage]
+	/// Subtasks indexed by parent [key: parent hash id, value: Vec<subtask hash id>]
+	pub(super) type Subtasks<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BoundedVec<T::Hash, T::MaxSubtasks>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn receipts)]
+	/// Receipts of closed Tasks, kept after the task is removed [key: hash id, value: TaskReceipt]
+	pub(super) type Receipts<T: Config> = StorageMap<_, Twox64Concat, T::Hash, TaskReceipt<T>>;
+
+	#[pallet::storage]
+	/// Receipts indexed by the initiator and volunteers of the task [key1: AccountID, key2: hash id, value: ()]
+	pub(super) type ReceiptsByAccount<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, T::Hash, ()>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn ratings)]
+	/// Ratings of closed Tasks [key1: hash id, key2: AccountID of rater, value: Rating]
+	pub(super) type Ratings<T: Config> = StorageDoubleMap<_, Twox64Concat, T::Hash, Blake2_128Concat, T::AccountId, Rating<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn task_versions)]
+	/// Version history of Tasks [key: hash id, value: Vec<TaskVersion>]
+	pub(super) type TaskVersions<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BoundedVec<TaskVersion<T>, T::MaxTaskVersions>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn deliverables)]
+	/// Revisions of the work delivered for Tasks [key: hash id, value: Vec<Deliverable>]
+	pub(super) type Deliverables<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BoundedVec<Deliverable<T>, T::MaxRevisions>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn disputes)]
+	/// Open disputes about Tasks [key: hash id, value: Dispute]
+	pub(super) type Disputes<T: Config> = StorageMap<_, Twox64Concat, T::Hash, Dispute<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn dispute_timeouts)]
+	/// Disputes that time out at a block [key: BlockNumber, value: Vec<hash id>]
+	pub(super) type DisputeTimeouts<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, BoundedVec<T::Hash, T::MaxDeadlinesPerBlock>, ValueQuery>;
+
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -157,14 +812,101 @@ pub mod pallet {
 		/// Event for creation of task [AccountID, hash id]
 		TaskCreated(T::AccountId, T::Hash),
 
+		/// Volunteer applied for task [AccountID, hash id]
+		TaskApplied(T::AccountId, T::Hash),
+
+		/// Volunteer withdrew application for task [AccountID, hash id]
+		ApplicationWithdrawn(T::AccountId, T::Hash),
+
 		/// Task assigned to new account [AccountID, hash id]
 		TaskAssigned(T::AccountId, T::Hash),
 
 		/// Task completed by assigned account [AccountID, hash id]
 		TaskCompleted(T::AccountId, T::Hash),
 
+		/// Completed task accepted by initiator [AccountID, hash id]
+		TaskAccepted(T::AccountId, T::Hash),
+
+		/// Completed task rejected by initiator [AccountID, hash id, reason hash]
+		TaskRejected(T::AccountId, T::Hash, T::Hash),
+
+		/// Parent and prerequisites of a task set [AccountID, hash id]
+		DependenciesSet(T::AccountId, T::Hash),
+
+		/// Team proposed by volunteer [AccountID, hash id]
+		TeamProposed(T::AccountId, T::Hash),
+
+		/// Team approved by initiator [AccountID, hash id]
+		TeamApproved(T::AccountId, T::Hash),
+
+		/// Task abandoned by volunteer [AccountID, hash id]
+		TaskAbandoned(T::AccountId, T::Hash),
+
+		/// Volunteer unassigned from task by initiator [AccountID of volunteer, hash id]
+		TaskUnassigned(T::AccountId, T::Hash),
+
+		/// Task updated by initiator [AccountID, hash id]
+		TaskUpdated(T::AccountId, T::Hash),
+
 		/// Task removed [AccountID, hash id]
 		TaskRemoved(T::AccountId, T::Hash),
+
+		/// Task budget reserved in escrow [AccountID, hash id, budget]
+		BudgetReserved(T::AccountId, T::Hash, BalanceOf<T>),
+
+		/// Escrowed task budget paid to volunteer [AccountID, hash id, budget]
+		BudgetPaid(T::AccountId, T::Hash, BalanceOf<T>),
+
+		/// Escrowed task budget returned to initiator [AccountID, hash id, budget]
+		BudgetUnreserved(T::AccountId, T::Hash, BalanceOf<T>),
+
+		/// Commission of a payout paid to the treasury [AccountID of treasury, hash id, commission]
+		CommissionPaid(T::AccountId, T::Hash, BalanceOf<T>),
+
+		/// Task budget topped up by a contributor [AccountID, hash id, amount]
+		TaskFunded(T::AccountId, T::Hash, BalanceOf<T>),
+
+		/// Contributions paid to the volunteer [hash id, Vec<(AccountID, amount)>]
+		ContributionsPaid(T::Hash, Vec<(T::AccountId, BalanceOf<T>)>),
+
+		/// Unpaid contribution returned to its funder [AccountID, hash id, amount]
+		ContributionRefunded(T::AccountId, T::Hash, BalanceOf<T>),
+
+		/// Task passed its deadline without being completed [AccountID, hash id]
+		TaskExpired(T::AccountId, T::Hash),
+
+		/// Milestone submitted by volunteer [AccountID, hash id, milestone index]
+		MilestoneSubmitted(T::AccountId, T::Hash, u32),
+
+		/// Milestone approved by initiator [AccountID, hash id, milestone index]
+		MilestoneApproved(T::AccountId, T::Hash, u32),
+
+		/// Skill tag added to the registry [tag]
+		TagRegistered(Vec<u8>),
+
+		/// Skill tag removed from the registry [tag]
+		TagUnregistered(Vec<u8>),
+
+		/// Deliverable submitted by volunteer [AccountID, hash id, revision]
+		DeliverableSubmitted(T::AccountId, T::Hash, u32),
+
+		/// Deliverable accepted by initiator [AccountID, hash id, revision]
+		DeliverableAccepted(T::AccountId, T::Hash, u32),
+
+		/// Dispute raised about a task [AccountID, hash id]
+		DisputeRaised(T::AccountId, T::Hash),
+
+		/// Arbitrator voted on a dispute [AccountID, hash id, share of volunteer]
+		DisputeVoted(T::AccountId, T::Hash, Percent),
+
+		/// Dispute resolved by arbitrators or timeout [hash id, share of volunteer]
+		DisputeResolved(T::Hash, Percent),
+
+		/// Deposit of a frivolous dispute slashed [AccountID, hash id, Balance]
+		DisputeDepositSlashed(T::AccountId, T::Hash, BalanceOf<T>),
+
+		/// Party of a closed task rated the other party [AccountID of rater, hash id, score]
+		TaskRated(T::AccountId, T::Hash, u8),
 	}
 
 	// Errors inform users that something went wrong.
@@ -184,6 +926,175 @@ pub mod pallet {
 		NoPermissionToComplete,
 		/// This account has no Profile yet. 
 		NoProfile,
+		/// Not enough free balance to reserve the task budget
+		InsufficientBalanceToReserve,
+		/// The reserved balance does not cover the task budget
+		InsufficientReservedBalance,
+		/// The deadline must be a block in the future
+		DeadlineInPast,
+		/// Reached maximum number of tasks with a deadline in the same block
+		ExceedMaxDeadlinesPerBlock,
+		/// Only the initiator of task has the rights to accept or reject task
+		OnlyInitiatorReviewsTask,
+		/// The task has not been submitted for review
+		TaskNotInReview,
+		/// Task in review must be accepted or rejected first
+		TaskInReview,
+		/// Task in progress can not be removed
+		TaskInProgress,
+		/// Only the initiator of task can assign it to an applicant
+		OnlyInitiatorAssignsTask,
+		/// The initiator can not apply for own task
+		InitiatorCannotApply,
+		/// Applications are only accepted for tasks that have not been started
+		TaskNotOpenForApplications,
+		/// This account has already applied for the task
+		AlreadyApplied,
+		/// Reached maximum number of applications for the task
+		ExceedMaxApplicants,
+		/// This account has not applied for the task
+		NotApplicant,
+		/// Milestone shares must be non-zero and add up to 100%
+		InvalidMilestoneShares,
+		/// Reached maximum number of milestones for a task
+		ExceedMaxMilestones,
+		/// The task has no milestones
+		NoMilestones,
+		/// Tasks with milestones are completed and accepted per milestone
+		TaskHasMilestones,
+		/// The given milestone doesn't exist
+		MilestoneNotExist,
+		/// Milestones must be submitted in order
+		MilestoneOutOfOrder,
+		/// Milestone has already been submitted
+		MilestoneAlreadySubmitted,
+		/// Milestone has not been submitted for approval
+		MilestoneNotSubmitted,
+		/// Only the initiator can update the task
+		OnlyInitiatorUpdatesTask,
+		/// The parent or prerequisite task does not exist
+		UnknownDependency,
+		/// The same prerequisite is listed more than once
+		DuplicateDependency,
+		/// Reached maximum number of prerequisites for a task
+		ExceedMaxPrerequisites,
+		/// The dependency would make a task depend on itself
+		DependencyCycle,
+		/// The dependency chain is deeper than allowed
+		DependencyTooDeep,
+		/// Not all prerequisites of the task are closed
+		PrerequisitesNotClosed,
+		/// The title of the task is too long
+		TitleTooLong,
+		/// The specification of the task is too long
+		SpecificationTooLong,
+		/// Team shares must be non-zero and add up to 100%
+		InvalidTeamShares,
+		/// The volunteer has to be part of the team
+		TeamMustIncludeVolunteer,
+		/// The same account is part of the team more than once
+		DuplicateTeamMember,
+		/// Reached maximum number of volunteers in a team
+		ExceedMaxTeamSize,
+		/// Reached maximum number of contributors to a task
+		ExceedMaxContributors,
+		/// Contributions must be greater than zero
+		ZeroContribution,
+		/// No team has been proposed for the task
+		NoTeamProposed,
+		/// Only the initiator can approve the team
+		OnlyInitiatorApprovesTeam,
+		/// The task is not in progress
+		TaskNotInProgress,
+		/// Only the volunteer can abandon the task
+		OnlyVolunteerAbandonsTask,
+		/// Only the initiator can unassign the volunteer
+		OnlyInitiatorUnassignsTask,
+		/// The volunteer can not be unassigned before the grace period is over
+		GracePeriodNotOver,
+		/// Task nonce overflowed
+		TaskNonceOverflow,
+		/// Reached maximum number of task versions
+		ExceedMaxTaskVersions,
+		/// Skill tag is too long
+		TagTooLong,
+		/// Skill tag is already registered
+		TagAlreadyExists,
+		/// Skill tag is not registered
+		UnknownTag,
+		/// The same skill tag is used more than once
+		DuplicateTag,
+		/// Reached maximum number of skill tags for a task
+		ExceedMaxTagsPerTask,
+		/// No deliverable has been submitted for the task
+		NoDeliverable,
+		/// Only the latest deliverable can be accepted
+		DeliverableNotLatest,
+		/// Deliverable content identifier is too long
+		DeliverableTooLong,
+		/// Reached maximum number of deliverable revisions
+		ExceedMaxRevisions,
+		/// Only the initiator or volunteer can dispute a task
+		NotTaskParty,
+		/// The task has not been closed
+		TaskNotClosed,
+		/// Ratings must be between 1 and 5
+		InvalidRating,
+		/// This party already rated the task
+		AlreadyRated,
+		/// The task was closed too long ago to be rated
+		RatingPeriodOver,
+		/// The task is being disputed
+		TaskDisputed,
+		/// The task is not being disputed
+		NoDispute,
+		/// Disputes are only resolved by arbitrator votes or timeout
+		OnlyArbitratorsResolveDispute,
+		/// This account can not arbitrate the dispute
+		NotArbitrator,
+		/// The initiator and volunteer can not arbitrate their own dispute
+		PartyCannotArbitrate,
+		/// This arbitrator already voted on the dispute
+		AlreadyVoted,
+		/// Reached maximum number of arbitrators for a dispute
+		ExceedMaxArbitrators,
+		/// Task has already been started
+		TaskAlreadyStarted,
+		/// Task has not been started yet
+		TaskNotStarted,
+		/// Task has already been submitted for review
+		TaskAlreadySubmitted,
+		/// Task has already been closed
+		TaskAlreadyClosed,
+		/// Task has already expired
+		TaskAlreadyExpired,
+		/// Only the runtime can expire tasks
+		OnlySystemExpiresTask,
+		/// The account does not have enough reputation to take new tasks
+		ReputationTooLow,
+		/// Reached maximum number of tasks depending on a task
+		ExceedMaxDependents,
+		/// Reached maximum number of subtasks
+		ExceedMaxSubtasks,
+		/// The initiator can not be a member of the task's team
+		InitiatorCannotJoinTeam,
+		/// The budget is below the minimum task budget
+		BudgetTooLow,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+
+		// Expire all unfinished tasks whose deadline is the current block
+		// and resolve disputes that timed out.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			Self::expire_tasks(now).saturating_add(Self::timeout_disputes(now))
+		}
+
+		// Re-key tasks created before ids were derived from a nonce and bound their fields.
+		fn on_runtime_upgrade() -> Weight {
+			Self::migrate_task_ids().saturating_add(Self::migrate_bounded_tasks())
+		}
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -191,152 +1102,1295 @@ pub mod pallet {
 	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Function call that creates tasks.  [ origin, specification, budget, deadline]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn create_task(origin: OriginFor<T>, title: Vec<u8>, specification: Vec<u8>, budget: BalanceOf<T>, deadline: u32) -> DispatchResultWithPostInfo {
+		/// Function call that creates tasks.  [ origin, specification, budget, deadline, tags]
+		#[transactional]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2 + T::MaxTagsPerTask::get() as Weight, 3 + T::MaxTagsPerTask::get() as Weight))]
+		pub fn create_task(origin: OriginFor<T>, title: Vec<u8>, specification: Vec<u8>, budget: BalanceOf<T>, deadline: T::BlockNumber, tags: Vec<Vec<u8>>) -> DispatchResultWithPostInfo {
 			
 			// Check that the extrinsic was signed and get the signer.
 			let signer = ensure_signed(origin)?;
 
 			// Update storage.
-			let task_id = Self::new_task(&signer, &title, &specification, &budget, &deadline)?;
+			let task_id = Self::new_task(&signer, &title, &specification, &budget, &deadline, tags)?;
 			
-			// TODO: Check if user has balance to create task
-			// T::Currency::reserve(&signer, budget).map_err(|_| "locker can't afford to lock the amount requested")?;
+			// Lock the budget in escrow until the task is completed or removed
+			Self::reserve_budget(&task_id, &signer, budget)?;
 
 			// Emit a Task Created Event.
+			Self::deposit_event(Event::BudgetReserved(signer.clone(), task_id, budget));
 			Self::deposit_event(Event::TaskCreated(signer, task_id));
 			// Return a successful DispatchResultWithPostInfo
 			Ok(().into())
 		}
 
-		/// Function call that starts a task by assigning new task owner. [origin, task_id]
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
-		pub fn start_task(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
+		/// Function call that creates a task whose budget is paid in an asset. [origin, asset_id, title, specification, budget, deadline, tags]
+		#[transactional]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3 + T::MaxTagsPerTask::get() as Weight, 6 + T::MaxTagsPerTask::get() as Weight))]
+		pub fn create_task_with_asset(origin: OriginFor<T>, asset_id: T::AssetId, title: Vec<u8>, specification: Vec<u8>, budget: BalanceOf<T>, deadline: T::BlockNumber, tags: Vec<Vec<u8>>) -> DispatchResultWithPostInfo {
 			
 			// Check that the extrinsic was signed and get the signer.
 			let signer = ensure_signed(origin)?;
 
-			// Assign task and update storage.
-			Self::assign_task(&signer, &task_id)?;
-
-			// Emit a Task Assigned Event.
-			Self::deposit_event(Event::TaskAssigned(signer, task_id));
+			// Update storage.
+			let task_id = Self::new_task(&signer, &title, &specification, &budget, &deadline, tags)?;
+			<TaskAssets<T>>::insert(task_id, asset_id);
 			
-			Ok(())
+			// Move the budget into the escrow of the pallet until the task is completed or removed
+			Self::reserve_budget(&task_id, &signer, budget)?;
+
+			// Emit a Task Created Event.
+			Self::deposit_event(Event::BudgetReserved(signer.clone(), task_id, budget));
+			Self::deposit_event(Event::TaskCreated(signer, task_id));
+
+			Ok(().into())
 		}
 
-		/// Function that completes a task [origin, task_id]
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,1))]
-		pub fn complete_task(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
+		/// Function that corrects a task that has not been started [origin, task_id, title, specification, budget, deadline]
+		#[transactional]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4,5))]
+		pub fn update_task(origin: OriginFor<T>, task_id: T::Hash, title: Vec<u8>, specification: Vec<u8>, budget: BalanceOf<T>, deadline: T::BlockNumber) -> DispatchResult {
 			
 			// Check that the extrinsic was signed and get the signer.
 			let signer = ensure_signed(origin)?;
 
-			// Complete task and update storage.
-			Self::mark_finished(&signer, &task_id)?;
+			// Update task, escrow and version history.
+			Self::edit_task(&signer, &task_id, title, specification, budget, deadline)?;
 
-			// Emit a Task Completed Event.
-			Self::deposit_event(Event::TaskCompleted(signer, task_id));
+			// Emit a Task Updated Event.
+			Self::deposit_event(Event::TaskUpdated(signer, task_id));
 			
 			Ok(())
 		}
 
-		/// Function to remove task. [origin, task_id]
+		/// Function that sets the parent and prerequisites of a task that has not been started [origin, task_id, parent, prerequisites]
 		#[transactional]
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
-		pub fn remove_task(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(
+			3 + (T::MaxPrerequisites::get() as Weight).saturating_mul(T::MaxDependencyDepth::get() as Weight + 4) + T::MaxDependencyDepth::get() as Weight,
+			5 + 3 * T::MaxPrerequisites::get() as Weight
+		))]
+		pub fn set_dependencies(origin: OriginFor<T>, task_id: T::Hash, parent: Option<T::Hash>, prerequisites: Vec<T::Hash>) -> DispatchResult {
 			
 			// Check that the extrinsic was signed and get the signer.
 			let signer = ensure_signed(origin)?;
 
-			// Complete task and update storage.
-			Self::delete_task(&signer, &task_id)?;
+			// Validate and store dependencies.
+			Self::edit_dependencies(&signer, &task_id, parent, prerequisites)?;
 
-			// Emit a Task Removed Event.
-			Self::deposit_event(Event::TaskRemoved(signer, task_id));
+			// Emit a Dependencies Set Event.
+			Self::deposit_event(Event::DependenciesSet(signer, task_id));
 			
 			Ok(())
 		}
-	}
+
+		/// Function that adds a skill tag to the registry [origin, tag]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		pub fn register_tag(origin: OriginFor<T>, tag: Vec<u8>) -> DispatchResult {
+			
+			// Check that the extrinsic comes from the tag registry origin.
+			T::TagOrigin::ensure_origin(origin)?;
+
+			// Register tag and update storage.
+			let bounded: TagOf<T> = tag.clone().try_into().map_err(|_| <Error<T>>::TagTooLong)?;
+			ensure!(!<SkillTags<T>>::contains_key(&bounded), <Error<T>>::TagAlreadyExists);
+			<SkillTags<T>>::insert(bounded, ());
+
+			// Emit a Tag Registered Event.
+			Self::deposit_event(Event::TagRegistered(tag));
+			
+			Ok(())
+		}
+
+		/// Function that removes a skill tag from the registry [origin, tag]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		pub fn unregister_tag(origin: OriginFor<T>, tag: Vec<u8>) -> DispatchResult {
+			
+			// Check that the extrinsic comes from the tag registry origin.
+			T::TagOrigin::ensure_origin(origin)?;
+
+			// Unregister tag and update storage.
+			let bounded: TagOf<T> = tag.clone().try_into().map_err(|_| <Error<T>>::TagTooLong)?;
+			ensure!(<SkillTags<T>>::contains_key(&bounded), <Error<T>>::UnknownTag);
+			<SkillTags<T>>::remove(bounded);
+
+			// Emit a Tag Unregistered Event.
+			Self::deposit_event(Event::TagUnregistered(tag));
+			
+			Ok(())
+		}
+
+		/// Function call that applies for a task with an optional counter-offer price. [origin, task_id, price, pitch]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,1))]
+		pub fn apply_for_task(origin: OriginFor<T>, task_id: T::Hash, price: Option<BalanceOf<T>>, pitch: T::Hash) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Store application.
+			Self::new_application(&signer, &task_id, price, pitch)?;
+
+			// Emit a Task Applied Event.
+			Self::deposit_event(Event::TaskApplied(signer, task_id));
+			
+			Ok(())
+		}
+
+		/// Function call that withdraws an application for a task. [origin, task_id]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		pub fn withdraw_application(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Remove application.
+			Self::remove_application(&signer, &task_id)?;
+
+			// Emit an Application Withdrawn Event.
+			Self::deposit_event(Event::ApplicationWithdrawn(signer, task_id));
+			
+			Ok(())
+		}
+
+		/// Function call that starts a task by assigning it to an applicant. [origin, task_id, applicant]
+		#[transactional]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,5))]
+		pub fn assign_to_applicant(origin: OriginFor<T>, task_id: T::Hash, applicant: T::AccountId) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Assign task and update storage.
+			Self::assign_task(&signer, &applicant, &task_id)?;
+
+			// Emit a Task Assigned Event.
+			Self::deposit_event(Event::TaskAssigned(applicant, task_id));
+			
+			Ok(())
+		}
+
+		/// Function that completes a task [origin, task_id]
+		#[transactional]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,1))]
+		pub fn complete_task(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Tasks with milestones are completed through submit_milestone
+			ensure!(Self::milestones(&task_id).is_empty(), <Error<T>>::TaskHasMilestones);
+
+			// Work can only be reviewed once something has been delivered
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+			task.status.transition::<T>(TaskAction::Complete, Self::role_of(&task, &signer))?;
+			ensure!(!Self::deliverables(&task_id).is_empty(), <Error<T>>::NoDeliverable);
+
+			// Complete task and update storage.
+			Self::mark_finished(&signer, &task_id)?;
+
+			// Emit a Task Completed Event.
+			Self::deposit_event(Event::TaskCompleted(signer, task_id));
+			
+			Ok(())
+		}
+
+		/// Function that submits a deliverable of a task in progress [origin, task_id, content]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,1))]
+		pub fn submit_deliverable(origin: OriginFor<T>, task_id: T::Hash, content: Vec<u8>) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Store deliverable and update storage.
+			let revision = Self::new_deliverable(&signer, &task_id, content)?;
+
+			// Emit a Deliverable Submitted Event.
+			Self::deposit_event(Event::DeliverableSubmitted(signer, task_id, revision));
+			
+			Ok(())
+		}
+
+		/// Function for the volunteer to propose a team and the split of the budget [origin, task_id, members]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,1))]
+		pub fn propose_team(origin: OriginFor<T>, task_id: T::Hash, members: Vec<(T::AccountId, Percent)>) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Store proposal.
+			Self::new_team_proposal(&signer, &task_id, members)?;
+
+			// Emit a Team Proposed Event.
+			Self::deposit_event(Event::TeamProposed(signer, task_id));
+			
+			Ok(())
+		}
+
+		/// Function for the initiator to approve the proposed team [origin, task_id]
+		#[transactional]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3 + 2 * T::MaxTeamSize::get() as Weight, 2 + 2 * T::MaxTeamSize::get() as Weight))]
+		pub fn approve_team(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Approve team and update storage.
+			Self::approve_team_proposal(&signer, &task_id)?;
+
+			// Emit a Team Approved Event.
+			Self::deposit_event(Event::TeamApproved(signer, task_id));
+			
+			Ok(())
+		}
+
+		/// Function for any account to add to the escrowed budget of an open task [origin, task_id, amount]
+		#[transactional]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,2))]
+		pub fn fund_task(origin: OriginFor<T>, task_id: T::Hash, amount: BalanceOf<T>) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Reserve the contribution and update storage.
+			Self::new_contribution(&signer, &task_id, amount)?;
+
+			// Emit a Task Funded Event.
+			Self::deposit_event(Event::TaskFunded(signer, task_id, amount));
+			
+			Ok(())
+		}
+
+		/// Function for the volunteer to back out of a task in progress [origin, task_id]
+		#[transactional]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4,6))]
+		pub fn abandon_task(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Return task to initiator and update storage.
+			Self::release_task(&signer, &task_id, TaskAction::Abandon)?;
+
+			// Emit a Task Abandoned Event.
+			Self::deposit_event(Event::TaskAbandoned(signer, task_id));
+			
+			Ok(())
+		}
+
+		/// Function for the initiator to take back a task from a silent volunteer [origin, task_id]
+		#[transactional]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(5,6))]
+		pub fn unassign_task(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Return task to initiator and update storage.
+			let volunteer = Self::release_task(&signer, &task_id, TaskAction::Unassign)?;
+
+			// Emit a Task Unassigned Event.
+			Self::deposit_event(Event::TaskUnassigned(volunteer, task_id));
+			
+			Ok(())
+		}
+
+		/// Function that accepts a completed task, pays the volunteer and rewards reputation [origin, task_id, revision]
+		#[transactional]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(6,5) + Pallet::<T>::settlement_weight())]
+		pub fn accept_task(origin: OriginFor<T>, task_id: T::Hash, revision: u32) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Tasks with milestones are accepted through approve_milestone
+			ensure!(Self::milestones(&task_id).is_empty(), <Error<T>>::TaskHasMilestones);
+
+			// The payment refers to the latest deliverable
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+			task.status.transition::<T>(TaskAction::Accept, Self::role_of(&task, &signer))?;
+			let latest = Self::latest_deliverable(&task_id).ok_or(<Error<T>>::NoDeliverable)?;
+			ensure!(latest == revision, <Error<T>>::DeliverableNotLatest);
+
+			// Accept task and update storage.
+			Self::accept_completed_task(&signer, &task_id)?;
+
+			// Emit a Task Accepted Event.
+			Self::deposit_event(Event::DeliverableAccepted(signer.clone(), task_id, revision));
+			Self::deposit_event(Event::TaskAccepted(signer, task_id));
+			
+			Ok(())
+		}
+
+		/// Function that rejects a completed task and returns it to the volunteer [origin, task_id, reason]
+		#[transactional]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4,4))]
+		pub fn reject_task(origin: OriginFor<T>, task_id: T::Hash, reason: T::Hash) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Reject task and update storage.
+			Self::reject_completed_task(&signer, &task_id)?;
+
+			// Emit a Task Rejected Event.
+			Self::deposit_event(Event::TaskRejected(signer, task_id, reason));
+			
+			Ok(())
+		}
+
+		/// Function call that creates a task with milestones. [origin, title, specification, budget, deadline, tags, milestones]
+		#[transactional]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2 + T::MaxTagsPerTask::get() as Weight, 4 + T::MaxTagsPerTask::get() as Weight))]
+		pub fn create_task_with_milestones(origin: OriginFor<T>, title: Vec<u8>, specification: Vec<u8>, budget: BalanceOf<T>, deadline: T::BlockNumber, tags: Vec<Vec<u8>>, milestones: Vec<(T::Hash, Percent)>) -> DispatchResultWithPostInfo {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Update storage.
+			let task_id = Self::new_task(&signer, &title, &specification, &budget, &deadline, tags)?;
+			Self::new_milestones(&task_id, milestones)?;
+			
+			// Lock the budget in escrow until the milestones are approved or the task is removed
+			Self::reserve_budget(&task_id, &signer, budget)?;
+
+			// Emit a Task Created Event.
+			Self::deposit_event(Event::BudgetReserved(signer.clone(), task_id, budget));
+			Self::deposit_event(Event::TaskCreated(signer, task_id));
+
+			Ok(().into())
+		}
+
+		/// Function that submits the next milestone of a task for approval [origin, task_id, index]
+		#[transactional]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,3))]
+		pub fn submit_milestone(origin: OriginFor<T>, task_id: T::Hash, index: u32) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Submit milestone and update storage.
+			Self::mark_milestone_submitted(&signer, &task_id, index)?;
+
+			// Emit a Milestone Submitted Event.
+			Self::deposit_event(Event::MilestoneSubmitted(signer, task_id, index));
+			
+			Ok(())
+		}
+
+		/// Function that approves a submitted milestone and pays its share of the budget [origin, task_id, index]
+		#[transactional]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(5,5) + Pallet::<T>::settlement_weight())]
+		pub fn approve_milestone(origin: OriginFor<T>, task_id: T::Hash, index: u32) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Approve milestone, pay volunteer and update storage.
+			let closed = Self::mark_milestone_approved(&signer, &task_id, index)?;
+
+			// Emit a Milestone Approved Event.
+			Self::deposit_event(Event::MilestoneApproved(signer.clone(), task_id, index));
+			if closed {
+				Self::deposit_event(Event::TaskAccepted(signer, task_id));
+			}
+			
+			Ok(())
+		}
+
+		/// Function that disputes a started task and freezes its escrow [origin, task_id]
+		#[transactional]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,4))]
+		pub fn raise_dispute(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Dispute task and update storage.
+			Self::new_dispute(&signer, &task_id)?;
+
+			// Emit a Dispute Raised Event.
+			Self::deposit_event(Event::DisputeRaised(signer, task_id));
+			
+			Ok(())
+		}
+
+		/// Function for arbitrators to vote on the share of the budget the volunteer receives [origin, task_id, volunteer_share]
+		#[transactional]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(6,7) + Pallet::<T>::settlement_weight())]
+		pub fn vote_on_dispute(origin: OriginFor<T>, task_id: T::Hash, volunteer_share: Percent) -> DispatchResult {
+			
+			// Accounts of the arbitrator origin can vote on any dispute,
+			// other signers only on disputes of their DAO's tasks.
+			let signer = match T::ArbitratorOrigin::try_origin(origin) {
+				Ok(arbitrator) => arbitrator,
+				Err(origin) => {
+					let signer = ensure_signed(origin)?;
+					ensure!(T::Arbitrators::arbitrators(&task_id).contains(&signer), <Error<T>>::NotArbitrator);
+					signer
+				},
+			};
+
+			// Vote and update storage.
+			let reached_quorum = Self::new_vote(&signer, &task_id, volunteer_share)?;
+
+			// Emit a Dispute Voted Event.
+			Self::deposit_event(Event::DisputeVoted(signer, task_id, volunteer_share));
+
+			// Resolve the dispute once enough arbitrators have voted
+			if reached_quorum {
+				Self::resolve_dispute(&task_id)?;
+			}
+			
+			Ok(())
+		}
+
+		/// Function for the initiator or volunteer of a closed task to rate the other party [origin, task_id, score, review]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,2))]
+		pub fn rate_task(origin: OriginFor<T>, task_id: T::Hash, score: u8, review: T::Hash) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Store rating and update the profile of the other party.
+			Self::new_rating(&signer, &task_id, score, review)?;
+
+			// Emit a Task Rated Event.
+			Self::deposit_event(Event::TaskRated(signer, task_id, score));
+			
+			Ok(())
+		}
+
+		/// Function to remove task. [origin, task_id]
+		#[transactional]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(
+			12 + T::MaxContributors::get() as Weight + T::MaxTagsPerTask::get() as Weight +
+				T::MaxPrerequisites::get() as Weight + T::MaxDependents::get() as Weight + T::MaxSubtasks::get() as Weight,
+			20 + T::MaxContributors::get() as Weight + T::MaxTagsPerTask::get() as Weight +
+				T::MaxPrerequisites::get() as Weight + T::MaxDependents::get() as Weight + T::MaxSubtasks::get() as Weight
+		))]
+		pub fn remove_task(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
+			
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Complete task and update storage.
+			Self::delete_task(&signer, &task_id)?;
+
+			// Emit a Task Removed Event.
+			Self::deposit_event(Event::TaskRemoved(signer, task_id));
+			
+			Ok(())
+		}
+	}
 	
 	// *** Helper functions *** //
 	impl<T:Config> Pallet<T> {
 
-		pub fn new_task(from_initiator: &T::AccountId, title: &[u8], specification: &[u8], budget: &BalanceOf<T>, deadline: &u32) -> Result<T::Hash, Error<T>> {
+		// Upper bound of the weight of paying out a closed task, which grows with its team and contributors
+		pub fn settlement_weight() -> Weight {
+			let team = (T::MaxTeamSize::get() as Weight).max(1);
+			let contributors = T::MaxContributors::get() as Weight;
+
+			// The budget and every contribution are split across the team, the rest of a contribution is refunded
+			let transfers = team.saturating_mul(1 + contributors).saturating_add(contributors);
+
+			// Every volunteer and the initiator collect reputation and a receipt, tags are dropped from the index
+			// and the task leaves its organization
+			let accounts = team.saturating_add(1);
+			let operations = transfers.saturating_mul(3)
+				.saturating_add(accounts.saturating_mul(2))
+				.saturating_add(T::MaxTagsPerTask::get() as Weight)
+				.saturating_add(2);
+			T::DbWeight::get().reads_writes(operations, operations)
+		}
+
+		pub fn new_task(from_initiator: &T::AccountId, title: &[u8], specification: &[u8], budget: &BalanceOf<T>, deadline: &T::BlockNumber, tags: Vec<Vec<u8>>) -> Result<T::Hash, Error<T>> {
 
 			// Ensure user has a profile before creating a task
-			ensure!(pallet_profile::Pallet::<T>::has_profile(from_initiator).unwrap(), <Error<T>>::NoProfile);
+			ensure!(pallet_profile::Pallet::<T>::has_profile(from_initiator).is_ok(), <Error<T>>::NoProfile);
+
+			// Ensure the deadline can still be reached
+			ensure!(*deadline > <frame_system::Pallet<T>>::block_number(), <Error<T>>::DeadlineInPast);
+
+			// Ensure the task pays for the deadline slot it takes
+			ensure!(*budget >= T::MinTaskBudget::get(), <Error<T>>::BudgetTooLow);
+
+			// Ensure tags are registered and unique
+			let tags = Self::registered_tags(tags)?;
 			
 			// Init Task Object
 			let task = Task::<T> {
-				title: title.to_vec(),
-				specification: specification.to_vec(),
+				title: title.to_vec().try_into().map_err(|_| <Error<T>>::TitleTooLong)?,
+				specification: specification.to_vec().try_into().map_err(|_| <Error<T>>::SpecificationTooLong)?,
 				initiator: from_initiator.clone(),
 				volunteer: from_initiator.clone(),
-				status: Created,
+				status: TaskStatus::Created,
 				budget: *budget,
 				current_owner: from_initiator.clone(),
 				deadline: *deadline,
+				tags,
 			};
 
-			// Create hash of task
-			let task_id = T::Hashing::hash_of(&task);
+			// Derive a stable id that doesn't change when the task is edited
+			let nonce = Self::task_nonce();
+			let task_id = T::Hashing::hash_of(&(from_initiator, nonce));
+			let new_nonce = nonce.checked_add(1).ok_or(<Error<T>>::TaskNonceOverflow)?;
+			<TaskNonce<T>>::put(new_nonce);
 
 			// Performs this operation first because as it may fail
 			<TasksOwned<T>>::try_mutate(&from_initiator, |tasks_vec| {
 				tasks_vec.try_push(task_id)
 			}).map_err(|_| <Error<T>>::ExceedMaxTasksOwned)?;
+
+			// Index task by deadline so it can be expired
+			<ExpiringTasks<T>>::try_mutate(deadline, |tasks_vec| {
+				tasks_vec.try_push(task_id)
+			}).map_err(|_| <Error<T>>::ExceedMaxDeadlinesPerBlock)?;
 			
+			// Index task by skill tag so volunteers can find it
+			for tag in task.tags.iter() {
+				<TasksByTag<T>>::insert(tag, task_id, ());
+			}
+
+			// Record the first version of the task
+			Self::record_version(&task_id, &task)?;
+
 			// Insert task into Hashmap
 			<Tasks<T>>::insert(task_id, task);
 
-			// Increase task count
-			let new_count = Self::task_count().checked_add(1).ok_or(<Error<T>>::TaskCountOverflow)?;
-			<TaskCount<T>>::put(new_count);
+			// Increase task count
+			let new_count = Self::task_count().checked_add(1).ok_or(<Error<T>>::TaskCountOverflow)?;
+			<TaskCount<T>>::put(new_count);
+
+			Ok(task_id)
+		}
+
+		pub fn edit_task(from_initiator: &T::AccountId, task_id: &T::Hash, title: Vec<u8>, specification: Vec<u8>, budget: BalanceOf<T>, deadline: T::BlockNumber) -> Result<(), Error<T>> {
+			// Check if task exists
+			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Only the initiator can edit a task that has not been started
+			task.status.transition::<T>(TaskAction::Update, Self::role_of(&task, from_initiator))?;
+
+			// Ensure the new deadline can still be reached
+			ensure!(deadline > <frame_system::Pallet<T>>::block_number(), <Error<T>>::DeadlineInPast);
+			ensure!(budget >= T::MinTaskBudget::get(), <Error<T>>::BudgetTooLow);
+
+			// Re-escrow the difference when the budget changes
+			if budget > task.budget {
+				let difference = budget - task.budget;
+				Self::reserve_budget(task_id, from_initiator, difference)?;
+				Self::deposit_event(Event::BudgetReserved(from_initiator.clone(), *task_id, difference));
+			} else if budget < task.budget {
+				let difference = task.budget - budget;
+				Self::release_balance(task_id, from_initiator, difference)?;
+				Self::deposit_event(Event::BudgetUnreserved(from_initiator.clone(), *task_id, difference));
+			}
+
+			// Move the task in the deadline index
+			if deadline != task.deadline {
+				<ExpiringTasks<T>>::try_mutate(deadline, |tasks_vec| {
+					tasks_vec.try_push(*task_id)
+				}).map_err(|_| <Error<T>>::ExceedMaxDeadlinesPerBlock)?;
+				<ExpiringTasks<T>>::mutate_exists(task.deadline, |maybe_tasks| {
+					if let Some(tasks_vec) = maybe_tasks {
+						tasks_vec.retain(|id| id != task_id);
+						if tasks_vec.is_empty() {
+							*maybe_tasks = None;
+						}
+					}
+				});
+			}
+
+			task.title = title.try_into().map_err(|_| <Error<T>>::TitleTooLong)?;
+			task.specification = specification.try_into().map_err(|_| <Error<T>>::SpecificationTooLong)?;
+			task.budget = budget;
+			task.deadline = deadline;
+			Self::record_version(task_id, &task)?;
+			<Tasks<T>>::insert(task_id, task);
+
+			Ok(())
+		}
+
+		// Appends the current fields of a task to its version history, dropping the oldest version once full
+		pub fn record_version(task_id: &T::Hash, task: &Task<T>) -> Result<(), Error<T>> {
+			let version = TaskVersion::<T> {
+				updated_at: <frame_system::Pallet<T>>::block_number(),
+				title: T::Hashing::hash(&task.title),
+				specification: T::Hashing::hash(&task.specification),
+				budget: task.budget,
+				deadline: task.deadline,
+			};
+
+			<TaskVersions<T>>::try_mutate(task_id, |versions| {
+				if versions.len() as u32 >= T::MaxTaskVersions::get() && !versions.is_empty() {
+					versions.remove(0);
+				}
+				versions.try_push(version)
+			}).map_err(|_| <Error<T>>::ExceedMaxTaskVersions)
+		}
+
+		// Converts tags into a bounded set of registered tags
+		pub fn registered_tags(tags: Vec<Vec<u8>>) -> Result<BoundedVec<TagOf<T>, T::MaxTagsPerTask>, Error<T>> {
+			let mut registered: BoundedVec<TagOf<T>, T::MaxTagsPerTask> = BoundedVec::default();
+			for tag in tags {
+				let tag: TagOf<T> = tag.try_into().map_err(|_| <Error<T>>::TagTooLong)?;
+				ensure!(<SkillTags<T>>::contains_key(&tag), <Error<T>>::UnknownTag);
+				ensure!(!registered.contains(&tag), <Error<T>>::DuplicateTag);
+				registered.try_push(tag).map_err(|_| <Error<T>>::ExceedMaxTagsPerTask)?;
+			}
+
+			Ok(registered)
+		}
+
+		// Removes a task from the skill tag index once it is no longer open
+		pub fn untag_task(task_id: &T::Hash, task: &Task<T>) {
+			for tag in task.tags.iter() {
+				<TasksByTag<T>>::remove(tag, task_id);
+			}
+		}
+
+		// Open tasks with the given skill tag, used by the runtime API
+		pub fn tasks_by_tag(tag: Vec<u8>) -> Vec<T::Hash> {
+			match TagOf::<T>::try_from(tag) {
+				Ok(tag) => <TasksByTag<T>>::iter_key_prefix(tag).collect(),
+				Err(_) => Vec::new(),
+			}
+		}
+
+		pub fn new_application(applicant: &T::AccountId, task_id: &T::Hash, price: Option<BalanceOf<T>>, pitch: T::Hash) -> Result<(), Error<T>> {
+			// Check if task exists
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Only tasks that have not been started accept applications
+			ensure!(task.status == TaskStatus::Created, <Error<T>>::TaskNotOpenForApplications);
+			ensure!(task.initiator != *applicant, <Error<T>>::InitiatorCannotApply);
+			ensure!(pallet_profile::Pallet::<T>::can_take_tasks(applicant), <Error<T>>::ReputationTooLow);
+			ensure!(price.map_or(true, |price| price >= T::MinTaskBudget::get()), <Error<T>>::BudgetTooLow);
+
+			<Applications<T>>::try_mutate(task_id, |applications| {
+				ensure!(!applications.iter().any(|a| a.applicant == *applicant), <Error<T>>::AlreadyApplied);
+
+				let application = Application::<T> {
+					applicant: applicant.clone(),
+					price,
+					pitch,
+				};
+				applications.try_push(application).map_err(|_| <Error<T>>::ExceedMaxApplicants)
+			})
+		}
+
+		pub fn remove_application(applicant: &T::AccountId, task_id: &T::Hash) -> Result<(), Error<T>> {
+			<Applications<T>>::try_mutate_exists(task_id, |maybe_applications| {
+				let applications = maybe_applications.as_mut().ok_or(<Error<T>>::NotApplicant)?;
+				let index = applications.iter().position(|a| a.applicant == *applicant).ok_or(<Error<T>>::NotApplicant)?;
+				applications.remove(index);
+
+				if applications.is_empty() {
+					*maybe_applications = None;
+				}
+				Ok(())
+			})
+		}
+
+		pub fn assign_task(from_initiator: &T::AccountId, to: &T::AccountId, task_id: &T::Hash) -> Result<(), Error<T>> {
+			// Check if task exists
+			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Check if the account may start the task
+			let status = task.status.transition::<T>(TaskAction::Start, Self::role_of(&task, from_initiator))?;
+
+			// Tasks can only start once everything they depend on is done
+			ensure!(Self::prerequisites(task_id).iter().all(|id| {
+				Self::tasks(id).map_or(false, |prerequisite| prerequisite.status == TaskStatus::Closed)
+			}), <Error<T>>::PrerequisitesNotClosed);
+
+			// The volunteer may have been penalized since applying
+			ensure!(pallet_profile::Pallet::<T>::can_take_tasks(to), <Error<T>>::ReputationTooLow);
+
+			// Find the application of the selected volunteer
+			let application = Self::applications(task_id).iter()
+				.find(|a| a.applicant == *to)
+				.cloned()
+				.ok_or(<Error<T>>::NotApplicant)?;
+
+			// Adjust the escrowed budget to the counter-offer of the applicant
+			if let Some(price) = application.price {
+				if price > task.budget {
+					Self::reserve_budget(task_id, &task.initiator, price - task.budget)?;
+				} else {
+					Self::release_balance(task_id, &task.initiator, task.budget - price)?;
+				}
+				task.budget = price;
+			}
+
+			// Applications are no longer needed once the task is assigned
+			<Applications<T>>::remove(task_id);
+
+			// Remove task ownership from previous owner
+			let prev_owner = task.current_owner.clone(); 
+			<TasksOwned<T>>::try_mutate(&prev_owner, |owned| {
+				if let Some(index) = owned.iter().position(|&id| id == *task_id) {
+					owned.swap_remove(index);
+					return Ok(());
+				}
+				Err(())
+			}).map_err(|_| <Error<T>>::TaskNotExist)?;
+
+			// Change task properties and insert
+			task.current_owner = to.clone();
+			task.volunteer = to.clone();
+			task.status = status;
+			<Tasks<T>>::insert(task_id, task);
+
+			// Assign task to volunteer
+			<TasksOwned<T>>::try_mutate(to, |vec| {
+				vec.try_push(*task_id)
+			}).map_err(|_| <Error<T>>::ExceedMaxTasksOwned)?;
+
+			// The volunteer's grace period starts now
+			Self::record_activity(task_id);
+
+			Ok(())
+		}
+
+
+		// Returns a task in progress to the initiator and penalizes the volunteer.
+		// Returns the account of the released volunteer.
+		pub fn release_task(from: &T::AccountId, task_id: &T::Hash, action: TaskAction) -> Result<T::AccountId, Error<T>> {
+			// Check if task exists
+			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Volunteers abandon tasks, initiators unassign them
+			let status = task.status.transition::<T>(action, Self::role_of(&task, from))?;
+
+			// Initiators have to give the volunteer the grace period
+			if action == TaskAction::Unassign {
+				let last_activity = Self::last_activity(task_id).unwrap_or_else(Zero::zero);
+				let now = <frame_system::Pallet<T>>::block_number();
+				ensure!(now >= last_activity.saturating_add(T::UnassignGracePeriod::get()), <Error<T>>::GracePeriodNotOver);
+			}
+
+			// Return ownership to the initiator
+			let volunteer = task.volunteer.clone();
+			let initiator = task.initiator.clone();
+			<TasksOwned<T>>::mutate(&task.current_owner, |owned| {
+				owned.retain(|id| id != task_id)
+			});
+			<TasksOwned<T>>::try_mutate(&initiator, |owned| {
+				owned.try_push(*task_id)
+			}).map_err(|_| <Error<T>>::ExceedMaxTasksOwned)?;
+
+			// The team of the released volunteer is dissolved
+			Self::remove_team_ownership(task_id, &task);
+			<Teams<T>>::remove(task_id);
+			<ProposedTeams<T>>::remove(task_id);
+
+			// Work of the released volunteer is discarded, approved milestones stay paid
+			<Deliverables<T>>::remove(task_id);
+			<LastActivity<T>>::remove(task_id);
+			<Milestones<T>>::mutate_exists(task_id, |maybe_milestones| {
+				if let Some(milestones) = maybe_milestones {
+					for index in 0..milestones.len() {
+						if let Some(milestone) = milestones.get_mut(index) {
+							if milestone.status == MilestoneStatus::Submitted {
+								milestone.status = MilestoneStatus::Pending;
+							}
+						}
+					}
+				}
+			});
+
+			task.volunteer = initiator.clone();
+			task.current_owner = initiator;
+			task.status = status;
+			<Tasks<T>>::insert(task_id, task);
+
+			// Penalize the volunteer if they have a profile
+			Self::penalize(&volunteer, PenaltyReason::AbandonedTask);
+
+			Ok(volunteer)
+		}
+
+		// Moves tasks stored under `hash_of(task)` to `hash_of(initiator, nonce)`. Before storage
+		// version 1 tasks were only referenced by `Tasks` and `TasksOwned`. The mapping from old
+		// to new ids is kept in `LegacyTaskIds` so other pallets can update their references.
+		// Budgets of open tasks are escrowed and budgets of closed tasks are paid to the volunteer.
+		pub fn migrate_task_ids() -> Weight {
+			if Self::on_chain_storage_version() >= 1 {
+				return 0;
+			}
+
+			let mut reads: Weight = 1;
+			let mut writes: Weight = 1;
+
+			let old_ids: Vec<T::Hash> = <Tasks<T>>::iter_keys().collect();
+			for old_id in old_ids {
+				reads += 1;
+				let old_key = <Tasks<T>>::hashed_key_for(old_id);
+				let raw = match unhashed::get_raw(&old_key) {
+					Some(raw) => raw,
+					None => continue,
+				};
+
+				let mut task = match LegacyTask::<T>::decode(&mut &raw[..]) {
+					Ok(task) => task,
+					Err(_) => {
+						log::warn!("task {:?} could not be decoded and keeps its id", old_id);
+						continue;
+					},
+				};
+
+				let nonce = Self::task_nonce();
+				let new_id = T::Hashing::hash_of(&(&task.initiator, nonce));
+				<TaskNonce<T>>::put(nonce.saturating_add(1));
+
+				// Legacy budgets were not escrowed, the initiator paid the volunteer when a closed task
+				// was removed. Closed tasks are paid out now, open tasks are escrowed under their new id.
+				reads += 1;
+				writes += 2;
+				if task.status == TaskStatus::Closed {
+					let paid = <T as self::Config>::Currency::transfer(&task.initiator, &task.volunteer, task.budget, ExistenceRequirement::KeepAlive);
+					if paid.is_err() {
+						log::warn!("budget of closed task {:?} could not be paid", old_id);
+					}
+				} else if Self::reserve_budget(&new_id, &task.initiator, task.budget).is_err() {
+					// The budget is lowered to what the initiator can still escrow
+					let available = <T as self::Config>::Currency::free_balance(&task.initiator).min(task.budget);
+					let escrow = if Self::reserve_budget(&new_id, &task.initiator, available).is_ok() { available } else { Zero::zero() };
+					log::warn!("budget of task {:?} is lowered to the {:?} its initiator could escrow", old_id, escrow);
+					task.budget = escrow;
+				}
+
+				unhashed::kill(&old_key);
+				unhashed::put(&<Tasks<T>>::hashed_key_for(new_id), &task);
+				<LegacyTaskIds<T>>::insert(old_id, new_id);
+				writes += 4;
+			}
+
+			// Point owners at the new ids. Identical tasks used to collide, so ids are deduplicated
+			// and ids of overwritten tasks are dropped.
+			<TasksOwned<T>>::translate::<BoundedVec<T::Hash, T::MaxTasksOwned>, _>(|_, owned| {
+				reads += 1;
+				writes += 1;
+				let mut migrated: BoundedVec<T::Hash, T::MaxTasksOwned> = BoundedVec::default();
+				for old_id in owned.iter() {
+					if let Some(new_id) = Self::legacy_task_ids(old_id) {
+						if !migrated.contains(&new_id) {
+							// Fits, since there are never more new ids than old ones
+							let _ = migrated.try_push(new_id);
+						}
+					}
+				}
+				Some(migrated)
+			});
+
+			StorageVersion::new(1).put::<Self>();
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		// Truncates titles and specifications of tasks stored before storage version 2
+		// to the configured limits, converts their deadlines to block numbers and adds empty tags.
+		// Open tasks are scheduled to expire, or expired at once when their deadline has passed.
+		pub fn migrate_bounded_tasks() -> Weight {
+			if Self::on_chain_storage_version() >= 2 {
+				return 0;
+			}
+
+			let mut reads: Weight = 1;
+			let mut writes: Weight = 1;
+			let mut open = Vec::new();
+
+			<Tasks<T>>::translate::<LegacyTask<T>, _>(|task_id, mut task| {
+				reads += 1;
+				writes += 1;
+				if task.status.transition::<T>(TaskAction::Expire, TaskRole::System).is_ok() {
+					open.push(task_id);
+				}
+				if task.title.len() > T::MaxTitleLength::get() as usize ||
+					task.specification.len() > T::MaxSpecificationLength::get() as usize {
+					log::warn!("truncating oversize title or specification of task {:?}", task_id);
+				}
+				task.title.truncate(T::MaxTitleLength::get() as usize);
+				task.specification.truncate(T::MaxSpecificationLength::get() as usize);
+
+				Some(Task {
+					title: task.title.try_into().ok()?,
+					specification: task.specification.try_into().ok()?,
+					initiator: task.initiator,
+					volunteer: task.volunteer,
+					current_owner: task.current_owner,
+					status: task.status,
+					budget: task.budget,
+					deadline: task.deadline.into(),
+					tags: BoundedVec::default(),
+				})
+			});
+
+			// Legacy deadlines were not indexed. Open tasks whose deadline has passed expire now,
+			// the others are scheduled at their deadline or the next block with a free slot.
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut weight: Weight = 0;
+			for task_id in open {
+				let mut task = match Self::tasks(task_id) {
+					Some(task) => task,
+					None => continue,
+				};
+				if task.deadline <= now {
+					weight = weight.saturating_add(Self::expire_task(&task_id));
+					continue;
+				}
+
+				let mut deadline = task.deadline;
+				while <ExpiringTasks<T>>::try_mutate(deadline, |tasks_vec| tasks_vec.try_push(task_id)).is_err() {
+					reads += 1;
+					deadline = deadline.saturating_add(1u32.into());
+				}
+				if deadline != task.deadline {
+					log::warn!("expiry of task {:?} is moved to block {:?}", task_id, deadline);
+					task.deadline = deadline;
+					<Tasks<T>>::insert(task_id, task);
+					writes += 1;
+				}
+				reads += 2;
+				writes += 1;
+			}
+
+			STORAGE_VERSION.put::<Self>();
+
+			T::DbWeight::get().reads_writes(reads, writes).saturating_add(weight)
+		}
+
+		pub fn edit_dependencies(task_initiator: &T::AccountId, task_id: &T::Hash, parent: Option<T::Hash>, prerequisites: Vec<T::Hash>) -> Result<(), Error<T>> {
+			// Check if task exists
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Only the initiator can change dependencies of a task that has not been started
+			task.status.transition::<T>(TaskAction::Update, Self::role_of(&task, task_initiator))?;
+
+			// Ensure the parent exists and is not one of the task's own subtasks
+			if let Some(parent) = parent {
+				ensure!(<Tasks<T>>::contains_key(parent), <Error<T>>::UnknownDependency);
+				let mut ancestor = Some(parent);
+				for _ in 0..=T::MaxDependencyDepth::get() {
+					match ancestor {
+						Some(id) if id == *task_id => return Err(<Error<T>>::DependencyCycle),
+						Some(id) => ancestor = Self::parent_task(id),
+						None => break,
+					}
+				}
+				ensure!(ancestor.is_none(), <Error<T>>::DependencyTooDeep);
+			}
+
+			// Ensure prerequisites exist, are unique and don't depend on the task
+			for (index, prerequisite) in prerequisites.iter().enumerate() {
+				ensure!(<Tasks<T>>::contains_key(prerequisite), <Error<T>>::UnknownDependency);
+				ensure!(!prerequisites.iter().skip(index + 1).any(|id| id == prerequisite), <Error<T>>::DuplicateDependency);
+				ensure!(!Self::depends_on(prerequisite, task_id)?, <Error<T>>::DependencyCycle);
+			}
+			let prerequisites: BoundedVec<T::Hash, T::MaxPrerequisites> = prerequisites.try_into()
+				.map_err(|_| <Error<T>>::ExceedMaxPrerequisites)?;
+
+			// Replace previous dependencies
+			for prerequisite in <Prerequisites<T>>::take(task_id).iter() {
+				<Dependents<T>>::mutate_exists(prerequisite, |maybe_dependents| Self::remove_id(maybe_dependents, task_id));
+			}
+			for prerequisite in prerequisites.iter() {
+				<Dependents<T>>::try_mutate(prerequisite, |dependents| {
+					dependents.try_push(*task_id)
+				}).map_err(|_| <Error<T>>::ExceedMaxDependents)?;
+			}
+			if !prerequisites.is_empty() {
+				<Prerequisites<T>>::insert(task_id, prerequisites);
+			}
+
+			if let Some(previous) = <ParentTask<T>>::take(task_id) {
+				<Subtasks<T>>::mutate_exists(previous, |maybe_subtasks| Self::remove_id(maybe_subtasks, task_id));
+			}
+			if let Some(parent) = parent {
+				<ParentTask<T>>::insert(task_id, parent);
+				<Subtasks<T>>::try_mutate(parent, |subtasks| {
+					subtasks.try_push(*task_id)
+				}).map_err(|_| <Error<T>>::ExceedMaxSubtasks)?;
+			}
+
+			Ok(())
+		}
+
+		// Whether `target` is reachable from `task_id` through prerequisites
+		pub fn depends_on(task_id: &T::Hash, target: &T::Hash) -> Result<bool, Error<T>> {
+			if task_id == target {
+				return Ok(true);
+			}
+
+			let mut visited: Vec<T::Hash> = Vec::new();
+			let mut pending = sp_std::vec![(*task_id, 0u32)];
+			while let Some((id, depth)) = pending.pop() {
+				if visited.contains(&id) {
+					continue;
+				}
+				visited.push(id);
+				for prerequisite in Self::prerequisites(id).iter() {
+					if prerequisite == target {
+						return Ok(true);
+					}
+					ensure!(depth < T::MaxDependencyDepth::get(), <Error<T>>::DependencyTooDeep);
+					pending.push((*prerequisite, depth + 1));
+				}
+			}
+
+			Ok(false)
+		}
+
+		// Detaches a removed task from its parent, subtasks, prerequisites and dependents
+		pub fn remove_dependencies(task_id: &T::Hash) {
+			for prerequisite in <Prerequisites<T>>::take(task_id).iter() {
+				<Dependents<T>>::mutate_exists(prerequisite, |maybe_dependents| Self::remove_id(maybe_dependents, task_id));
+			}
+			for dependent in <Dependents<T>>::take(task_id).iter() {
+				<Prerequisites<T>>::mutate_exists(dependent, |maybe_prerequisites| Self::remove_id(maybe_prerequisites, task_id));
+			}
+
+			if let Some(parent) = <ParentTask<T>>::take(task_id) {
+				<Subtasks<T>>::mutate_exists(parent, |maybe_subtasks| Self::remove_id(maybe_subtasks, task_id));
+			}
+			for subtask in <Subtasks<T>>::take(task_id).iter() {
+				<ParentTask<T>>::remove(subtask);
+			}
+		}
+
+		// Removes an id from a bounded list of ids, and the list once it is empty
+		fn remove_id<S: Get<u32>>(maybe_ids: &mut Option<BoundedVec<T::Hash, S>>, task_id: &T::Hash) {
+			if let Some(ids) = maybe_ids {
+				ids.retain(|id| id != task_id);
+				if ids.is_empty() {
+					*maybe_ids = None;
+				}
+			}
+		}
+
+		// Page of the tasks currently owned by an account, used by the runtime API
+		pub fn tasks_by_owner(owner: T::AccountId, page: u32, page_size: u32) -> Vec<(T::Hash, Task<T>)> {
+			let owned = Self::tasks_owned(owner);
+			Self::page_of(owned.iter().filter_map(|id| Self::tasks(id).map(|task| (*id, task))), page, page_size)
+		}
+
+		// Page of the tasks in a status, used by the runtime API
+		pub fn tasks_by_status(status: TaskStatus, page: u32, page_size: u32) -> Vec<(T::Hash, Task<T>)> {
+			Self::page_of(<Tasks<T>>::iter().filter(|(_, task)| task.status == status), page, page_size)
+		}
+
+		// Page of the tasks created by an account, used by the runtime API
+		pub fn tasks_by_initiator(initiator: T::AccountId, page: u32, page_size: u32) -> Vec<(T::Hash, Task<T>)> {
+			Self::page_of(<Tasks<T>>::iter().filter(|(_, task)| task.initiator == initiator), page, page_size)
+		}
+
+		// Task with its id, used by the runtime API
+		pub fn task_details(task_id: T::Hash) -> Option<Task<T>> {
+			Self::tasks(task_id)
+		}
+
+		// Applicants of a task ranked by their endorsements for its tags, used by the runtime API.
+		// Applicants with as many endorsements keep the order they applied in.
+		pub fn ranked_applicants(task_id: T::Hash) -> Vec<(T::AccountId, u32)> {
+			let tags = Self::tasks(task_id).map(|task| task.tags).unwrap_or_default();
+			let mut applicants: Vec<(T::AccountId, u32)> = Self::applications(task_id).iter()
+				.map(|application| {
+					let endorsements = tags.iter().fold(0u32, |total, tag| {
+						total.saturating_add(pallet_profile::Pallet::<T>::endorsements_of(&application.applicant, tag))
+					});
+					(application.applicant.clone(), endorsements)
+				})
+				.collect();
+			applicants.sort_by(|a, b| b.1.cmp(&a.1));
+			applicants
+		}
+
+		fn page_of<I: Iterator<Item = (T::Hash, Task<T>)>>(tasks: I, page: u32, page_size: u32) -> Vec<(T::Hash, Task<T>)> {
+			tasks.skip(page.saturating_mul(page_size) as usize).take(page_size as usize).collect()
+		}
+
+		// Subtasks of a task, used by the runtime API
+		pub fn subtasks(parent: T::Hash) -> Vec<T::Hash> {
+			<Subtasks<T>>::get(parent).into_inner()
+		}
+
+		// Every task the given task depends on, each listed with its direct prerequisites
+		pub fn dependency_tree(task_id: T::Hash) -> Vec<(T::Hash, Vec<T::Hash>)> {
+			let mut tree: Vec<(T::Hash, Vec<T::Hash>)> = Vec::new();
+			if !<Tasks<T>>::contains_key(task_id) {
+				return tree;
+			}
+
+			let mut pending = sp_std::vec![task_id];
+			while let Some(id) = pending.pop() {
+				if tree.iter().any(|(visited, _)| *visited == id) {
+					continue;
+				}
+				let prerequisites = Self::prerequisites(id).to_vec();
+				pending.extend(prerequisites.iter().cloned());
+				tree.push((id, prerequisites));
+			}
+
+			tree
+		}
+
+		pub fn new_team_proposal(volunteer: &T::AccountId, task_id: &T::Hash, members: Vec<(T::AccountId, Percent)>) -> Result<(), Error<T>> {
+			// Check if task exists
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Only the volunteer of a task in progress can propose a team
+			task.status.transition::<T>(TaskAction::Complete, Self::role_of(&task, volunteer))?;
+
+			// Ensure the volunteer leads a team of unique members whose shares cover the whole budget
+			ensure!(members.iter().any(|(member, _)| member == volunteer), <Error<T>>::TeamMustIncludeVolunteer);
+			let total = members.iter().fold(0u32, |acc, (_, share)| acc + share.deconstruct() as u32);
+			ensure!(total == 100, <Error<T>>::InvalidTeamShares);
+			ensure!(members.iter().all(|(_, share)| !share.is_zero()), <Error<T>>::InvalidTeamShares);
+			for (index, (member, _)) in members.iter().enumerate() {
+				ensure!(!members.iter().skip(index + 1).any(|(other, _)| other == member), <Error<T>>::DuplicateTeamMember);
+			}
+			ensure!(members.iter().all(|(member, _)| *member != task.initiator), <Error<T>>::InitiatorCannotJoinTeam);
+			ensure!(members.iter().all(|(member, _)| pallet_profile::Pallet::<T>::can_take_tasks(member)), <Error<T>>::ReputationTooLow);
+
+			let members: BoundedVec<(T::AccountId, Percent), T::MaxTeamSize> = members.try_into()
+				.map_err(|_| <Error<T>>::ExceedMaxTeamSize)?;
+			<ProposedTeams<T>>::insert(task_id, members);
+
+			Ok(())
+		}
+
+		pub fn approve_team_proposal(task_initiator: &T::AccountId, task_id: &T::Hash) -> Result<(), Error<T>> {
+			// Check if task exists
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Only the initiator can approve the team of a task in progress
+			ensure!(task.initiator == *task_initiator, <Error<T>>::OnlyInitiatorApprovesTeam);
+			ensure!(task.status == TaskStatus::InProgress, <Error<T>>::TaskNotInProgress);
+			ensure!(<ProposedTeams<T>>::contains_key(task_id), <Error<T>>::NoTeamProposed);
+
+			// Replace the previous team, if any
+			Self::remove_team_ownership(task_id, &task);
+			<Teams<T>>::insert(task_id, <ProposedTeams<T>>::take(task_id));
+			Self::add_team_ownership(task_id, &task.volunteer)?;
+
+			Ok(())
+		}
+
+		// Accounts that receive payouts and reputation for a task: the team, or the volunteer
+		pub fn volunteers_of(task_id: &T::Hash, volunteer: &T::AccountId) -> Vec<T::AccountId> {
+			let team = Self::teams(task_id);
+			if team.is_empty() {
+				return sp_std::vec![volunteer.clone()];
+			}
+			team.iter().map(|(member, _)| member.clone()).collect()
+		}
+
+		// Team members other than the volunteer also own the task while it is in progress
+		pub fn add_team_ownership(task_id: &T::Hash, volunteer: &T::AccountId) -> Result<(), Error<T>> {
+			for (member, _) in Self::teams(task_id).iter().filter(|(member, _)| member != volunteer) {
+				<TasksOwned<T>>::try_mutate(member, |owned| {
+					owned.try_push(*task_id)
+				}).map_err(|_| <Error<T>>::ExceedMaxTasksOwned)?;
+			}
+
+			Ok(())
+		}
+
+		pub fn remove_team_ownership(task_id: &T::Hash, task: &Task<T>) {
+			for (member, _) in Self::teams(task_id).iter().filter(|(member, _)| *member != task.volunteer) {
+				<TasksOwned<T>>::mutate(member, |owned| {
+					owned.retain(|id| id != task_id)
+				});
+			}
+		}
 
-			Ok(task_id)
+		// Pays the volunteer, or every team member by share, from the initiator's escrow
+		pub fn pay_volunteers(task_initiator: &T::AccountId, task_id: &T::Hash, volunteer: &T::AccountId, amount: BalanceOf<T>) -> Result<(), Error<T>> {
+			let team = Self::teams(task_id);
+			if team.is_empty() {
+				let paid = Self::transfer_balance(task_id, task_initiator, volunteer, amount)?;
+				Self::deposit_event(Event::BudgetPaid(volunteer.clone(), *task_id, paid));
+				return Ok(());
+			}
+
+			let mut remaining = amount;
+			for (index, (member, share)) in team.iter().enumerate() {
+				// The last member receives the remainder so no funds are lost to rounding
+				let payout = if index + 1 == team.len() { remaining } else { share.mul_floor(amount) };
+				remaining = remaining.saturating_sub(payout);
+				let paid = Self::transfer_balance(task_id, task_initiator, member, payout)?;
+				Self::deposit_event(Event::BudgetPaid(member.clone(), *task_id, paid));
+			}
+
+			Ok(())
 		}
 
-		pub fn assign_task(to: &T::AccountId, task_id: &T::Hash) -> Result<(), Error<T>> {
+		// Reserves a contribution to the budget of a task that is not yet in review
+		pub fn new_contribution(funder: &T::AccountId, task_id: &T::Hash, amount: BalanceOf<T>) -> Result<(), Error<T>> {
 			// Check if task exists
-			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
 
-			// Remove task ownership from previous owner
-			let prev_owner = task.current_owner.clone(); 
-			<TasksOwned<T>>::try_mutate(&prev_owner, |owned| {
-				if let Some(index) = owned.iter().position(|&id| id == *task_id) {
-					owned.swap_remove(index);
-					return Ok(());
+			// Anyone can fund a task that is created or in progress
+			task.status.transition::<T>(TaskAction::Fund, Self::role_of(&task, funder))?;
+			ensure!(!amount.is_zero(), <Error<T>>::ZeroContribution);
+
+			// Repeated contributions of the same funder are merged
+			<Contributions<T>>::try_mutate(task_id, |contributions| {
+				match contributions.iter().position(|(account, _)| account == funder) {
+					Some(index) => {
+						if let Some((_, total)) = contributions.get_mut(index) {
+							*total = total.saturating_add(amount);
+						}
+						Ok(())
+					},
+					None => contributions.try_push((funder.clone(), amount))
+						.map_err(|_| <Error<T>>::ExceedMaxContributors),
 				}
-				Err(())
-			}).map_err(|_| <Error<T>>::TaskNotExist)?;
-
-			// Change task properties and insert
-			task.current_owner = to.clone();
-			task.volunteer = to.clone();
-			task.status = TaskStatus::InProgress;
-			<Tasks<T>>::insert(task_id, task);
+			})?;
 
-			// Assign task to volunteer
-			<TasksOwned<T>>::try_mutate(to, |vec| {
-				vec.try_push(*task_id)
-			}).map_err(|_| <Error<T>>::ExceedMaxTasksOwned)?;
+			Self::reserve_budget(task_id, funder, amount)?;
 
 			Ok(())
 		}
 
+		// Pays `share` of every contribution to the volunteer, or the team, and refunds the rest to its funder.
+		// Returns the total amount paid.
+		pub fn settle_contributions(task_id: &T::Hash, volunteer: &T::AccountId, share: Percent) -> Result<BalanceOf<T>, Error<T>> {
+			let mut total = BalanceOf::<T>::zero();
+			let mut paid = Vec::new();
+			for (funder, amount) in <Contributions<T>>::take(task_id).into_iter() {
+				let payout = share.mul_floor(amount);
+				let refund = amount.saturating_sub(payout);
+				if !payout.is_zero() {
+					Self::pay_volunteers(&funder, task_id, volunteer, payout)?;
+					total = total.saturating_add(payout);
+					paid.push((funder.clone(), payout));
+				}
+				if !refund.is_zero() {
+					Self::release_balance(task_id, &funder, refund)?;
+					Self::deposit_event(Event::ContributionRefunded(funder, *task_id, refund));
+				}
+			}
+
+			if !paid.is_empty() {
+				Self::deposit_event(Event::ContributionsPaid(*task_id, paid));
+			}
+
+			Ok(total)
+		}
+
+		// Records that the volunteer worked on a task
+		pub fn record_activity(task_id: &T::Hash) {
+			<LastActivity<T>>::insert(task_id, <frame_system::Pallet<T>>::block_number());
+		}
 
 		pub fn mark_finished(to: &T::AccountId, task_id: &T::Hash) -> Result<(), Error<T>> {
 			
 			// Check if task exists
 			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
 
-			// Check if task is in progress before closing
-			ensure!(TaskStatus::InProgress == task.status, <Error<T>>::NoPermissionToComplete);
-
-			// TODO: Check if the volunteer is the one who finished task
-
+			// Check if the task is in progress and the signer is the volunteer
+			let status = task.status.transition::<T>(TaskAction::Complete, Self::role_of(&task, to))?;
 
 			// Remove task ownership from current signer 
 			<TasksOwned<T>>::try_mutate(&to, |owned| {
@@ -346,10 +2400,11 @@ pub mod pallet {
 				}
 				Err(())
 			}).map_err(|_| <Error<T>>::TaskNotExist)?;
+			Self::remove_team_ownership(task_id, &task);
 
-			// Set current owner to initiator
+			// Set current owner to initiator for review
 			task.current_owner = task.initiator.clone();
-			task.status = TaskStatus::Closed;
+			task.status = status;
 			let task_initiator = task.initiator.clone();
 
 			// Insert into update task
@@ -367,11 +2422,11 @@ pub mod pallet {
 			// Check if task exists
 			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
 			
-			//Check if the owner is the one who created task
-			ensure!(Self::is_task_initiator(task_id, task_initiator)?, <Error<T>>::OnlyInitiatorClosesTask);
+			// Check if the task can be removed and the signer is the one who created task
+			task.status.transition::<T>(TaskAction::Remove, Self::role_of(&task, task_initiator))?;
 
-			// Remove from ownership
-			<TasksOwned<T>>::try_mutate(&task_initiator, |owned| {
+			// Remove from ownership of the current owner
+			<TasksOwned<T>>::try_mutate(&task.current_owner, |owned| {
 				if let Some(index) = owned.iter().position(|&id| id == *task_id) {
 					owned.swap_remove(index);
 					return Ok(());
@@ -379,16 +2434,40 @@ pub mod pallet {
 				Err(())
 			}).map_err(|_| <Error<T>>::TaskNotExist)?;
 
-			// Transfer balance to volunteer
-			let volunteer = task.volunteer.clone();
-			let budget = task.budget;
-			Self::transfer_balance(task_initiator, &volunteer, budget)?;
+			// Return the budget to the initiator for tasks that were never started.
+			// Closed tasks have already been paid and expired tasks have already been refunded.
+			if task.status == TaskStatus::Created {
+				let budget = task.budget;
+				Self::release_balance(task_id, task_initiator, budget)?;
+				Self::deposit_event(Event::BudgetUnreserved(task_initiator.clone(), *task_id, budget));
+				Self::settle_contributions(task_id, &task.volunteer, Percent::zero())?;
+			}
 
-			// Reward reputation points to profiles who created/completed a task
-			Self::handle_reputation(task_id).expect("Add reputation works");
+			// Remove pending applications and milestones
+			<Applications<T>>::remove(task_id);
+			<Milestones<T>>::remove(task_id);
+			<Deliverables<T>>::remove(task_id);
+			<TaskVersions<T>>::remove(task_id);
+			<LastActivity<T>>::remove(task_id);
+			<Teams<T>>::remove(task_id);
+			<ProposedTeams<T>>::remove(task_id);
+			<TaskAssets<T>>::remove(task_id);
+			Self::remove_dependencies(task_id);
+
+			// Remove task from the deadline index
+			<ExpiringTasks<T>>::mutate_exists(task.deadline, |maybe_tasks| {
+				if let Some(tasks_vec) = maybe_tasks {
+					tasks_vec.retain(|id| id != task_id);
+					if tasks_vec.is_empty() {
+						*maybe_tasks = None;
+					}
+				}
+			});
 
 			// remove task once closed
+			Self::untag_task(task_id, &task);
 			<Tasks<T>>::remove(task_id);
+			T::Organizations::task_removed(task_id);
 
 			// Reduce task count
 			let new_count = Self::task_count().saturating_sub(1);
@@ -397,6 +2476,428 @@ pub mod pallet {
 			Ok(())
 		}
 
+		pub fn accept_completed_task(task_initiator: &T::AccountId, task_id: &T::Hash) -> Result<(), Error<T>> {
+			// Check if task exists
+			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Only the initiator can sign off on submitted work
+			let status = task.status.transition::<T>(TaskAction::Accept, Self::role_of(&task, task_initiator))?;
+
+			// Close task and update storage
+			task.status = status;
+			Self::untag_task(task_id, &task);
+			let volunteer = task.volunteer.clone();
+			let budget = Self::unpaid_budget(task_id, &task);
+			let total_budget = task.budget;
+			<Tasks<T>>::insert(task_id, task);
+
+			// Pay the volunteer, or the team, from escrow including every contribution
+			Self::pay_volunteers(task_initiator, task_id, &volunteer, budget)?;
+			let contributed = Self::settle_contributions(task_id, &volunteer, Percent::one())?;
+
+			// Keep a receipt of the work, which includes milestones that were paid before
+			Self::issue_receipt(task_id, total_budget.saturating_add(contributed));
+
+			// Reward reputation points to profiles who created/completed a task
+			Self::handle_reputation(task_id)?;
+
+			// The receipt keeps the organization of the closed task
+			T::Organizations::task_removed(task_id);
+
+			Ok(())
+		}
+
+		pub fn reject_completed_task(task_initiator: &T::AccountId, task_id: &T::Hash) -> Result<(), Error<T>> {
+			// Check if task exists
+			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Only the initiator can reject submitted work
+			let status = task.status.transition::<T>(TaskAction::Reject, Self::role_of(&task, task_initiator))?;
+
+			// The rejected milestone can be submitted again
+			<Milestones<T>>::mutate_exists(task_id, |maybe_milestones| {
+				if let Some(milestones) = maybe_milestones {
+					let last = milestones.len().saturating_sub(1);
+					if let Some(milestone) = milestones.get_mut(last) {
+						milestone.status = MilestoneStatus::Pending;
+					}
+				}
+			});
+
+			// Remove task ownership from initiator
+			<TasksOwned<T>>::try_mutate(&task_initiator, |owned| {
+				if let Some(index) = owned.iter().position(|&id| id == *task_id) {
+					owned.swap_remove(index);
+					return Ok(());
+				}
+				Err(())
+			}).map_err(|_| <Error<T>>::TaskNotExist)?;
+
+			// Tasks are not expired while in review, so a passed deadline expires the task in the next block
+			let now = <frame_system::Pallet<T>>::block_number();
+			if task.deadline <= now {
+				let deadline = now.saturating_add(1u32.into());
+				<ExpiringTasks<T>>::try_mutate(deadline, |tasks_vec| {
+					tasks_vec.try_push(*task_id)
+				}).map_err(|_| <Error<T>>::ExceedMaxDeadlinesPerBlock)?;
+				task.deadline = deadline;
+			}
+
+			// Return task to the volunteer
+			let volunteer = task.volunteer.clone();
+			task.current_owner = volunteer.clone();
+			task.status = status;
+			<Tasks<T>>::insert(task_id, task);
+
+			<TasksOwned<T>>::try_mutate(&volunteer, |vec| {
+				vec.try_push(*task_id)
+			}).map_err(|_| <Error<T>>::ExceedMaxTasksOwned)?;
+			Self::add_team_ownership(task_id, &volunteer)?;
+
+			// The volunteer's grace period restarts with the rejected work
+			Self::record_activity(task_id);
+
+			Ok(())
+		}
+
+		pub fn new_milestones(task_id: &T::Hash, milestones: Vec<(T::Hash, Percent)>) -> Result<(), Error<T>> {
+			// Ensure every milestone gets a share and the shares cover the whole budget
+			let total = milestones.iter().fold(0u32, |acc, (_, share)| acc + share.deconstruct() as u32);
+			ensure!(total == 100, <Error<T>>::InvalidMilestoneShares);
+			ensure!(milestones.iter().all(|(_, share)| !share.is_zero()), <Error<T>>::InvalidMilestoneShares);
+
+			let milestones: Vec<Milestone<T>> = milestones.into_iter()
+				.map(|(description, share)| Milestone::<T> {
+					description,
+					share,
+					status: MilestoneStatus::Pending,
+				})
+				.collect();
+			let milestones: BoundedVec<Milestone<T>, T::MaxMilestones> = milestones.try_into()
+				.map_err(|_| <Error<T>>::ExceedMaxMilestones)?;
+
+			<Milestones<T>>::insert(task_id, milestones);
+
+			Ok(())
+		}
+
+		pub fn mark_milestone_submitted(volunteer: &T::AccountId, task_id: &T::Hash, index: u32) -> Result<(), Error<T>> {
+			// Check if task exists
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+			let mut milestones = Self::milestones(task_id);
+			ensure!(!milestones.is_empty(), <Error<T>>::NoMilestones);
+
+			// Milestones can be submitted whenever the task could be completed
+			task.status.transition::<T>(TaskAction::Complete, Self::role_of(&task, volunteer))?;
+
+			// Only the first milestone that is not approved can be submitted
+			let next = milestones.iter().position(|m| m.status != MilestoneStatus::Approved)
+				.ok_or(<Error<T>>::MilestoneNotExist)?;
+			ensure!(index as usize == next, <Error<T>>::MilestoneOutOfOrder);
+
+			let is_last = next + 1 == milestones.len();
+			let milestone = milestones.get_mut(next).ok_or(<Error<T>>::MilestoneNotExist)?;
+			ensure!(milestone.status == MilestoneStatus::Pending, <Error<T>>::MilestoneAlreadySubmitted);
+			milestone.status = MilestoneStatus::Submitted;
+			<Milestones<T>>::insert(task_id, milestones);
+
+			// Submitting the last milestone puts the whole task in review
+			if is_last {
+				Self::mark_finished(volunteer, task_id)?;
+			}
+
+			Self::record_activity(task_id);
+
+			Ok(())
+		}
+
+		// Approves a submitted milestone and returns whether the task has been closed
+		pub fn mark_milestone_approved(task_initiator: &T::AccountId, task_id: &T::Hash, index: u32) -> Result<bool, Error<T>> {
+			// Check if task exists
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+			let mut milestones = Self::milestones(task_id);
+			ensure!(!milestones.is_empty(), <Error<T>>::NoMilestones);
+
+			// Only the initiator can approve milestones of started tasks that are not closed or disputed
+			task.status.transition::<T>(TaskAction::ApproveMilestone, Self::role_of(&task, task_initiator))?;
+
+			let index = index as usize;
+			let is_last = index + 1 == milestones.len();
+			let payout = Self::milestone_payout(&milestones, index, task.budget);
+			let milestone = milestones.get_mut(index).ok_or(<Error<T>>::MilestoneNotExist)?;
+			ensure!(milestone.status == MilestoneStatus::Submitted, <Error<T>>::MilestoneNotSubmitted);
+
+			// Approving the last milestone accepts the task, which pays the remaining budget
+			if is_last {
+				Self::accept_completed_task(task_initiator, task_id)?;
+			} else {
+				Self::pay_volunteers(task_initiator, task_id, &task.volunteer, payout)?;
+			}
+
+			milestone.status = MilestoneStatus::Approved;
+			<Milestones<T>>::insert(task_id, milestones);
+
+			Ok(is_last)
+		}
+
+		// Amount of the budget released when the milestone at `index` is approved.
+		// The last milestone receives the remainder so no funds are lost to rounding.
+		pub fn milestone_payout(milestones: &[Milestone<T>], index: usize, budget: BalanceOf<T>) -> BalanceOf<T> {
+			if index + 1 == milestones.len() {
+				let paid = milestones.iter().take(index)
+					.fold(BalanceOf::<T>::zero(), |acc, m| acc.saturating_add(m.share.mul_floor(budget)));
+				budget.saturating_sub(paid)
+			} else {
+				milestones.get(index).map(|m| m.share.mul_floor(budget)).unwrap_or_else(Zero::zero)
+			}
+		}
+
+		// Budget of a task that is still held in escrow
+		pub fn unpaid_budget(task_id: &T::Hash, task: &Task<T>) -> BalanceOf<T> {
+			let milestones = Self::milestones(task_id);
+			let paid = milestones.iter().enumerate()
+				.filter(|(_, m)| m.status == MilestoneStatus::Approved)
+				.fold(BalanceOf::<T>::zero(), |acc, (index, _)| acc.saturating_add(Self::milestone_payout(&milestones, index, task.budget)));
+			task.budget.saturating_sub(paid)
+		}
+
+		// Stores a new deliverable revision and returns its revision number
+		pub fn new_deliverable(volunteer: &T::AccountId, task_id: &T::Hash, content: Vec<u8>) -> Result<u32, Error<T>> {
+			// Check if task exists
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Deliverables can be submitted whenever the task could be completed
+			task.status.transition::<T>(TaskAction::Complete, Self::role_of(&task, volunteer))?;
+
+			let content: BoundedVec<u8, T::MaxDeliverableLength> = content.try_into()
+				.map_err(|_| <Error<T>>::DeliverableTooLong)?;
+			let revision = Self::latest_deliverable(task_id).map_or(0, |latest| latest.saturating_add(1));
+			let deliverable = Deliverable::<T> {
+				revision,
+				content,
+				submitted_at: <frame_system::Pallet<T>>::block_number(),
+			};
+
+			// Keep a bounded history by dropping the oldest revision
+			let mut deliverables = Self::deliverables(task_id);
+			if deliverables.len() as u32 >= T::MaxRevisions::get() && !deliverables.is_empty() {
+				deliverables.remove(0);
+			}
+			deliverables.try_push(deliverable).map_err(|_| <Error<T>>::ExceedMaxRevisions)?;
+			<Deliverables<T>>::insert(task_id, deliverables);
+
+			Self::record_activity(task_id);
+
+			Ok(revision)
+		}
+
+		// Revision number of the latest deliverable of a task
+		pub fn latest_deliverable(task_id: &T::Hash) -> Option<u32> {
+			Self::deliverables(task_id).last().map(|deliverable| deliverable.revision)
+		}
+
+		pub fn new_dispute(from: &T::AccountId, task_id: &T::Hash) -> Result<(), Error<T>> {
+			// Check if task exists
+			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Only the initiator and volunteer of a started task can dispute it
+			let status = task.status.transition::<T>(TaskAction::Dispute, Self::role_of(&task, from))?;
+
+			// Reserve the dispute deposit
+			let deposit = T::DisputeDeposit::get();
+			<T as self::Config>::Currency::reserve(from, deposit).map_err(|_| <Error<T>>::InsufficientBalanceToReserve)?;
+
+			// Schedule the timeout of the dispute
+			let timeout = <frame_system::Pallet<T>>::block_number().saturating_add(T::DisputeTimeout::get());
+			<DisputeTimeouts<T>>::try_mutate(timeout, |disputes| {
+				disputes.try_push(*task_id)
+			}).map_err(|_| <Error<T>>::ExceedMaxDeadlinesPerBlock)?;
+
+			let dispute = Dispute::<T> {
+				raised_by: from.clone(),
+				deliverable: Self::latest_deliverable(task_id),
+				deposit,
+				timeout,
+				votes: BoundedVec::default(),
+			};
+			<Disputes<T>>::insert(task_id, dispute);
+
+			// Freeze the task and its escrow
+			task.status = status;
+			<Tasks<T>>::insert(task_id, task);
+
+			Ok(())
+		}
+
+		// Records the vote of an arbitrator and returns whether the quorum has been reached
+		pub fn new_vote(arbitrator: &T::AccountId, task_id: &T::Hash, volunteer_share: Percent) -> Result<bool, Error<T>> {
+			// Check if task exists
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Parties of the dispute can not arbitrate it
+			ensure!(task.initiator != *arbitrator && task.volunteer != *arbitrator, <Error<T>>::PartyCannotArbitrate);
+
+			<Disputes<T>>::try_mutate(task_id, |maybe_dispute| {
+				let dispute = maybe_dispute.as_mut().ok_or(<Error<T>>::NoDispute)?;
+				ensure!(!dispute.votes.iter().any(|(voter, _)| voter == arbitrator), <Error<T>>::AlreadyVoted);
+				dispute.votes.try_push((arbitrator.clone(), volunteer_share))
+					.map_err(|_| <Error<T>>::ExceedMaxArbitrators)?;
+
+				Ok(dispute.votes.len() as u32 >= T::DisputeQuorum::get())
+			})
+		}
+
+		// Records a receipt of a closed task for its initiator and every volunteer
+		pub fn issue_receipt(task_id: &T::Hash, paid: BalanceOf<T>) {
+			let task = match Self::tasks(task_id) {
+				Some(task) => task,
+				None => return,
+			};
+
+			let receipt = TaskReceipt::<T> {
+				initiator: task.initiator.clone(),
+				volunteer: task.volunteer.clone(),
+				paid,
+				asset: Self::task_asset(task_id),
+				closed_at: <frame_system::Pallet<T>>::block_number(),
+				task_hash: T::Hashing::hash_of(&task),
+				organization: T::Organizations::organization(task_id),
+			};
+
+			<ReceiptsByAccount<T>>::insert(&task.initiator, task_id, ());
+			for volunteer in Self::volunteers_of(task_id, &task.volunteer).iter() {
+				<ReceiptsByAccount<T>>::insert(volunteer, task_id, ());
+			}
+			<Receipts<T>>::insert(task_id, receipt);
+		}
+
+		// Stores the rating of one party of a closed task for the other party
+		pub fn new_rating(rater: &T::AccountId, task_id: &T::Hash, score: u8, review: T::Hash) -> Result<(), Error<T>> {
+			// Only closed tasks have a receipt
+			let receipt = Self::receipts(task_id).ok_or(<Error<T>>::TaskNotClosed)?;
+
+			// The initiator rates the volunteer and the volunteer rates the initiator
+			let ratee = if *rater == receipt.initiator {
+				receipt.volunteer.clone()
+			} else if *rater == receipt.volunteer {
+				receipt.initiator.clone()
+			} else {
+				return Err(<Error<T>>::NotTaskParty);
+			};
+
+			ensure!((1..=5).contains(&score), <Error<T>>::InvalidRating);
+			ensure!(<frame_system::Pallet<T>>::block_number() <= receipt.closed_at.saturating_add(T::RatingPeriod::get()), <Error<T>>::RatingPeriodOver);
+			ensure!(!<Ratings<T>>::contains_key(task_id, rater), <Error<T>>::AlreadyRated);
+
+			// Ratings of tasks that paid more weigh more, but every rating counts
+			let weight = receipt.paid.saturated_into::<u128>().max(1);
+			pallet_profile::Pallet::<T>::add_rating(&ratee, score, weight);
+
+			// The rating also moves the reputation of the ratee if they have a profile
+			let context = Self::reputation_context(task_id, receipt.paid, Some(score));
+			Self::update_reputation(&ratee, &context)?;
+			<Ratings<T>>::insert(task_id, rater, Rating::<T> { ratee, score, review });
+
+			Ok(())
+		}
+
+		// Receipts of the tasks an account initiated or worked on
+		pub fn receipts_of(account: &T::AccountId) -> Vec<(T::Hash, TaskReceipt<T>)> {
+			<ReceiptsByAccount<T>>::iter_key_prefix(account)
+				.filter_map(|task_id| Self::receipts(task_id).map(|receipt| (task_id, receipt)))
+				.collect()
+		}
+
+		// Splits the remaining escrow according to the ruling and closes the task
+		#[transactional]
+		pub fn resolve_dispute(task_id: &T::Hash) -> Result<(), Error<T>> {
+			// Check if task and dispute exist
+			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+			let dispute = <Disputes<T>>::take(task_id).ok_or(<Error<T>>::NoDispute)?;
+			let status = task.status.transition::<T>(TaskAction::Resolve, TaskRole::System)?;
+
+			// Pay the volunteer's share and return the rest to the initiator
+			let ruling = Self::dispute_ruling(&dispute.votes);
+			let budget = Self::unpaid_budget(task_id, &task);
+			let payout = ruling.mul_floor(budget);
+			let refund = budget.saturating_sub(payout);
+			Self::pay_volunteers(&task.initiator, task_id, &task.volunteer, payout)?;
+			Self::release_balance(task_id, &task.initiator, refund)?;
+			let contributed = Self::settle_contributions(task_id, &task.volunteer, ruling)?;
+			let paid = task.budget.saturating_sub(budget).saturating_add(payout).saturating_add(contributed);
+
+			// The party the ruling goes against loses reputation
+			if ruling < Percent::from_percent(50) {
+				for volunteer in Self::volunteers_of(task_id, &task.volunteer) {
+					Self::penalize(&volunteer, PenaltyReason::LostDispute);
+				}
+			} else if ruling > Percent::from_percent(50) {
+				Self::penalize(&task.initiator, PenaltyReason::LostDispute);
+			}
+
+			// Slash the deposit of frivolous disputes, return it otherwise
+			let frivolous = (dispute.raised_by == task.volunteer && ruling.is_zero()) ||
+				(dispute.raised_by == task.initiator && ruling == Percent::one());
+			if frivolous {
+				let _ = <T as self::Config>::Currency::slash_reserved(&dispute.raised_by, dispute.deposit);
+				Self::deposit_event(Event::DisputeDepositSlashed(dispute.raised_by.clone(), *task_id, dispute.deposit));
+			} else {
+				<T as self::Config>::Currency::unreserve(&dispute.raised_by, dispute.deposit);
+			}
+
+			// Return ownership to the initiator
+			let initiator = task.initiator.clone();
+			if task.current_owner != initiator {
+				<TasksOwned<T>>::mutate(&task.current_owner, |owned| {
+					owned.retain(|id| id != task_id)
+				});
+				Self::remove_team_ownership(task_id, &task);
+				<TasksOwned<T>>::try_mutate(&initiator, |owned| {
+					owned.try_push(*task_id)
+				}).map_err(|_| <Error<T>>::ExceedMaxTasksOwned)?;
+			}
+
+			// Resolved disputes no longer time out
+			<DisputeTimeouts<T>>::mutate_exists(dispute.timeout, |maybe_disputes| {
+				if let Some(disputes) = maybe_disputes {
+					disputes.retain(|id| id != task_id);
+					if disputes.is_empty() {
+						*maybe_disputes = None;
+					}
+				}
+			});
+
+			task.current_owner = initiator.clone();
+			task.status = status;
+			Self::untag_task(task_id, &task);
+			<Tasks<T>>::insert(task_id, task);
+			Self::issue_receipt(task_id, paid);
+			T::Organizations::task_removed(task_id);
+
+			Self::deposit_event(Event::BudgetUnreserved(initiator, *task_id, refund));
+			Self::deposit_event(Event::DisputeResolved(*task_id, ruling));
+
+			Ok(())
+		}
+
+		// Median share of the votes, or an even split when nobody voted
+		pub fn dispute_ruling(votes: &[(T::AccountId, Percent)]) -> Percent {
+			let mut shares: Vec<Percent> = votes.iter().map(|(_, share)| *share).collect();
+			shares.sort();
+			shares.get(shares.len().saturating_sub(1) / 2).copied().unwrap_or_else(|| Percent::from_percent(50))
+		}
+
+		// Function to determine the role of an account for a task
+		pub fn role_of(task: &Task<T>, who: &T::AccountId) -> TaskRole {
+			if task.initiator == *who {
+				TaskRole::Initiator
+			} else if task.status != TaskStatus::Created && task.volunteer == *who {
+				TaskRole::Volunteer
+			} else {
+				TaskRole::Other
+			}
+		}
+
 		// Function to check if the current signer is the task_initiator
 		pub fn is_task_initiator(task_id: &T::Hash, task_closer: &T::AccountId) -> Result<bool, Error<T>> {
 			match Self::tasks(task_id) {
@@ -405,20 +2906,221 @@ pub mod pallet {
 			}
 		}
 
-		// Function to transfer balance from one account to another
+		// Account holding the escrowed budgets of tasks paid in an asset
+		pub fn escrow_account() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		// Function to lock a budget in escrow, in the native currency or the asset of the task
+		pub fn reserve_budget(task_id: &T::Hash, from: &T::AccountId, budget: BalanceOf<T>) -> Result<(), Error<T>> {
+			match Self::task_asset(task_id) {
+				Some(asset_id) => T::Assets::transfer(asset_id, from, &Self::escrow_account(), budget, false).map(|_| ()),
+				None => <T as self::Config>::Currency::reserve_named(task_id, from, budget),
+			}.map_err(|_| <Error<T>>::InsufficientBalanceToReserve)
+		}
+
+		// Account receiving commissions on tasks that are not owned by an organization
+		pub fn treasury_account() -> T::AccountId {
+			T::PalletId::get().into_sub_account(b"treasury")
+		}
+
+		// Treasury and commission that apply to payouts of a task
+		pub fn commission_of(task_id: &T::Hash) -> (T::AccountId, Permill) {
+			let treasury = T::Treasury::treasury(task_id).unwrap_or_else(Self::treasury_account);
+			let commission = T::Treasury::commission(task_id).unwrap_or_else(T::Commission::get);
+			(treasury, commission)
+		}
+
+		// Function to pay the escrowed budget from the initiator's reserve for the task to the volunteer.
+		// The commission goes to the treasury and the amount paid to the volunteer is returned.
 		#[transactional]
-		pub fn transfer_balance(task_initiator: &T::AccountId, task_volunteer: &T::AccountId, budget: BalanceOf<T>) -> Result<(), Error<T>> {
-			// TODO: Investigate why Currency transfer doesn't work 
-			// TODO: See proper testing https://docs.substrate.io/how-to-guides/v3/testing/transfer-function/
+		pub fn transfer_balance(task_id: &T::Hash, task_initiator: &T::AccountId, task_volunteer: &T::AccountId, budget: BalanceOf<T>) -> Result<BalanceOf<T>, Error<T>> {
+			let (treasury, commission) = Self::commission_of(task_id);
+			let fee = commission.mul_floor(budget);
+			let paid = budget.saturating_sub(fee);
+
+			Self::move_escrow(task_id, task_initiator, task_volunteer, paid)?;
+			if !fee.is_zero() {
+				Self::move_escrow(task_id, task_initiator, &treasury, fee)?;
+				Self::deposit_event(Event::CommissionPaid(treasury, *task_id, fee));
+			}
 
-			//let task_initiator = task.initiator.clone();
-			log::info!("budget {:?}.", budget);
-			log::info!("signer {:?}.", task_initiator);
-			log::info!("task_volunteer {:?}.", task_volunteer);
-			ensure!(<T as self::Config>::Currency::free_balance(task_initiator) >= budget, <Error<T>>::NotEnoughBalance);
-			let result = <T as self::Config>::Currency::transfer(task_initiator, task_volunteer, budget, ExistenceRequirement::KeepAlive);
-			log::info!("Result Output {:?}.", result);
+			Ok(paid)
+		}
+
+		// Function to move escrowed funds of a task from the reserve `from` holds for it to another account
+		pub fn move_escrow(task_id: &T::Hash, from: &T::AccountId, to: &T::AccountId, budget: BalanceOf<T>) -> Result<(), Error<T>> {
+			if let Some(asset_id) = Self::task_asset(task_id) {
+				return Self::transfer_from_escrow(asset_id, to, budget);
+			}
+
+			// Ensure the budget is still held in the escrow of the task
+			ensure!(<T as self::Config>::Currency::reserved_balance_named(task_id, from) >= budget, <Error<T>>::InsufficientReservedBalance);
+
+			// Move reserved funds directly into the free balance of the recipient
+			let remaining = <T as self::Config>::Currency::repatriate_reserved_named(task_id, from, to, budget, BalanceStatus::Free)
+				.map_err(|_| <Error<T>>::NotEnoughBalance)?;
+			ensure!(remaining.is_zero(), <Error<T>>::InsufficientReservedBalance);
+
+			Ok(())
+		}
+
+		// Function to return the escrowed budget to the initiator
+		pub fn release_balance(task_id: &T::Hash, task_initiator: &T::AccountId, budget: BalanceOf<T>) -> Result<(), Error<T>> {
+			if let Some(asset_id) = Self::task_asset(task_id) {
+				return Self::transfer_from_escrow(asset_id, task_initiator, budget);
+			}
+
+			// Ensure the budget is still held in the escrow of the task
+			ensure!(<T as self::Config>::Currency::reserved_balance_named(task_id, task_initiator) >= budget, <Error<T>>::InsufficientReservedBalance);
+
+			<T as self::Config>::Currency::unreserve_named(task_id, task_initiator, budget);
+
+			Ok(())
+		}
+
+		// Function to pay out an asset budget held by the pallet account
+		pub fn transfer_from_escrow(asset_id: T::AssetId, to: &T::AccountId, budget: BalanceOf<T>) -> Result<(), Error<T>> {
+			let escrow = Self::escrow_account();
+
+			// Ensure the budget is still held in escrow
+			ensure!(<T::Assets as fungibles::Inspect<T::AccountId>>::balance(asset_id, &escrow) >= budget, <Error<T>>::InsufficientReservedBalance);
+
+			T::Assets::transfer(asset_id, &escrow, to, budget, false).map_err(|_| <Error<T>>::NotEnoughBalance)?;
+
+			Ok(())
+		}
+
+		// Expires unfinished tasks whose deadline is reached and returns the escrowed budget.
+		// The amount of work is bounded by MaxDeadlinesPerBlock.
+		pub fn expire_tasks(now: T::BlockNumber) -> Weight {
+			let expiring = <ExpiringTasks<T>>::take(now);
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+			for task_id in expiring.iter() {
+				weight = weight.saturating_add(Self::expire_task(task_id));
+			}
+
+			weight
+		}
+
+		// Expires a task that is still open, returns the escrow and penalizes its volunteers
+		pub fn expire_task(task_id: &T::Hash) -> Weight {
+			let mut reads: Weight = 1;
+			let mut writes: Weight = 0;
+			let mut task = match Self::tasks(task_id) {
+				Some(task) => task,
+				None => return T::DbWeight::get().reads(reads),
+			};
+
+			// Only tasks that are still open can expire
+			let status = match task.status.transition::<T>(TaskAction::Expire, TaskRole::System) {
+				Ok(status) => status,
+				Err(_) => return T::DbWeight::get().reads(reads),
+			};
+
+			// Volunteers of started tasks missed the deadline
+			let initiator = task.initiator.clone();
+			if task.volunteer != initiator {
+				for volunteer in Self::volunteers_of(task_id, &task.volunteer) {
+					Self::penalize(&volunteer, PenaltyReason::MissedDeadline);
+					reads += 1;
+					writes += 1;
+				}
+			}
+
+			// Return ownership to the initiator
+			if task.current_owner != initiator {
+				<TasksOwned<T>>::mutate(&task.current_owner, |owned| {
+					owned.retain(|id| id != task_id)
+				});
+				Self::remove_team_ownership(task_id, &task);
+				if <TasksOwned<T>>::try_mutate(&initiator, |owned| owned.try_push(*task_id)).is_err() {
+					log::warn!("initiator {:?} can not own expired task {:?}", initiator, task_id);
+				}
+				reads += 2;
+				writes += 2;
+			}
+
+			// Return the unpaid escrowed budget to the initiator
+			let budget = Self::unpaid_budget(task_id, &task);
+			if Self::release_balance(task_id, &initiator, budget).is_err() {
+				log::warn!("budget of expired task {:?} can not be returned", task_id);
+			}
+			reads += 1;
+			writes += 1;
+
+			// Refund contributors from escrow
+			let contributors = Self::contributions(task_id).len() as Weight;
+			if Self::settle_contributions(task_id, &task.volunteer, Percent::zero()).is_err() {
+				log::warn!("contributions to expired task {:?} can not be refunded", task_id);
+			}
+			reads += 1 + contributors;
+			writes += 1 + contributors;
+
+			task.current_owner = initiator.clone();
+			task.status = status;
+			Self::untag_task(task_id, &task);
+			writes += task.tags.len() as Weight;
+			<Tasks<T>>::insert(task_id, task);
+			<Applications<T>>::remove(task_id);
+			T::Organizations::task_removed(task_id);
+			reads += 2;
+			writes += 4;
+
+			Self::deposit_event(Event::BudgetUnreserved(initiator.clone(), *task_id, budget));
+			Self::deposit_event(Event::TaskExpired(initiator, *task_id));
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		pub fn timeout_disputes(now: T::BlockNumber) -> Weight {
+			let timed_out = <DisputeTimeouts<T>>::take(now);
+			let mut reads: Weight = 1;
+			let mut writes: Weight = 1;
+			let mut settlements: Weight = 0;
+
+			for task_id in timed_out.iter() {
+				// Resolve with the votes cast so far, which pays out the task
+				reads += 6;
+				writes += 7;
+				settlements = settlements.saturating_add(Self::settlement_weight());
+				let error = match Self::resolve_dispute(task_id) {
+					Ok(()) => continue,
+					Err(error) => error,
+				};
+				log::warn!("dispute about task {:?} could not be resolved: {:?}", task_id, error);
+
+				// The escrow stays frozen until the dispute is resolved, so it times out again later
+				reads += 2;
+				writes += 2;
+				let timeout = now.saturating_add(T::DisputeTimeout::get());
+				let rescheduled = <Disputes<T>>::try_mutate(task_id, |maybe_dispute| {
+					let dispute = maybe_dispute.as_mut().ok_or(())?;
+					<DisputeTimeouts<T>>::try_mutate(timeout, |disputes| disputes.try_push(*task_id)).map_err(|_| ())?;
+					dispute.timeout = timeout;
+					Ok::<(), ()>(())
+				});
+				if rescheduled.is_err() {
+					log::warn!("dispute about task {:?} could not be rescheduled", task_id);
+				}
+			}
 
+			T::DbWeight::get().reads_writes(reads, writes).saturating_add(settlements)
+		}
+
+		// Penalizes the reputation of an account if it has a profile
+		pub fn penalize(who: &T::AccountId, reason: PenaltyReason) {
+			if pallet_profile::Pallet::<T>::has_profile(who).is_ok() {
+				let _ = pallet_profile::Pallet::<T>::penalize(who, reason);
+			}
+		}
+
+		// Updates the reputation of an account if it has a profile
+		pub fn update_reputation(who: &T::AccountId, context: &pallet_profile::ReputationContext<T::AccountId>) -> Result<(), Error<T>> {
+			if pallet_profile::Pallet::<T>::has_profile(who).is_ok() {
+				pallet_profile::Pallet::<T>::update_reputation(who, context).map_err(|_| <Error<T>>::NoProfile)?;
+			}
 			Ok(())
 		}
 
@@ -428,13 +3130,53 @@ pub mod pallet {
 			// Check if task exists
 			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
 
-			// Ensure that reputation is added only when task is in status Closed
+			// Ensure that reputation is added only when task is in status Closed.
+			// Initiators, volunteers and team members without a profile don't collect reputation.
 			if task.status == TaskStatus::Closed {
-				pallet_profile::Pallet::<T>::add_reputation(&task.initiator).expect("can add reputation");
-				pallet_profile::Pallet::<T>::add_reputation(&task.volunteer).expect("can add reputation");
+				let paid = Self::receipts(task_id).map(|receipt| receipt.paid).unwrap_or(task.budget);
+				let context = Self::reputation_context(task_id, paid, None);
+				Self::update_reputation(&task.initiator, &context)?;
+
+				// Team members are credited with their share of the budget
+				let team = Self::teams(task_id);
+				for volunteer in Self::volunteers_of(task_id, &task.volunteer) {
+					let share = team.iter()
+						.find(|(member, _)| *member == volunteer)
+						.map_or(Percent::one(), |(_, share)| *share);
+					let context = Self::reputation_context(task_id, share * paid, None);
+					Self::update_reputation(&volunteer, &context)?;
+				}
 			}
 
 			Ok(())
 		}
+
+		// Describes the work done on a task to the ReputationHandler of profiles
+		pub fn reputation_context(task_id: &T::Hash, budget: BalanceOf<T>, rating: Option<u8>) -> pallet_profile::ReputationContext<T::AccountId> {
+			// Removed tasks keep their receipt, but not their deadline or organization
+			let receipt = Self::receipts(task_id);
+			let closed_at = receipt.as_ref().map_or_else(<frame_system::Pallet<T>>::block_number, |receipt| receipt.closed_at);
+			let lateness = Self::tasks(task_id).map_or_else(Zero::zero, |task| closed_at.saturating_sub(task.deadline));
+			pallet_profile::ReputationContext {
+				budget: budget.saturated_into::<u128>(),
+				rating,
+				scope: receipt.map_or_else(|| T::Organizations::organization(task_id), |receipt| receipt.organization),
+				lateness: lateness.saturated_into::<u32>(),
+			}
+		}
+	}
+
+	// Skills of profiles are drawn from the skill tags of tasks
+	impl<T: Config> pallet_profile::SkillRegistry for Pallet<T> {
+		fn is_registered(skill: &[u8]) -> bool {
+			TagOf::<T>::try_from(skill.to_vec()).map_or(false, |tag| <SkillTags<T>>::contains_key(&tag))
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn register(skill: &[u8]) {
+			if let Ok(tag) = TagOf::<T>::try_from(skill.to_vec()) {
+				<SkillTags<T>>::insert(tag, ());
+			}
+		}
 	}
 }
\ No newline at end of file
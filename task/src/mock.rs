@@ -1,12 +1,12 @@
 
 use crate as pallet_task;
-use frame_support::parameter_types;
-use frame_system as system;
+use frame_support::{PalletId, parameter_types, traits::{OnInitialize, SortedMembers}};
+use frame_system::{self as system, EnsureRoot, EnsureSignedBy};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	BuildStorage, Permill,
 };
 
 
@@ -23,6 +23,7 @@ frame_support::construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Task: pallet_task::{Pallet, Call, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Profile: pallet_profile::{Pallet, Call, Storage, Event<T>},
 	}
 );
@@ -61,17 +62,56 @@ impl system::Config for Test {
 
 parameter_types! {
 	pub const ExistentialDeposit: u64 = 1;
+	// Task budgets are reserved under their task id, an account can fund at most 100 tasks at once
+	pub const MaxReserves: u32 = 100;
+}
+
+parameter_types! {
+	// Usernames are at most 16 bytes and interests at most 64 bytes
+	pub const MaxUsernameLength: u32 = 16;
+	pub const MaxInterestsLength: u32 = 64;
+	// Reputation decays slowly enough not to affect the other tests
+	pub const ReputationHalfLife: u64 = 1_000;
+	// Abandoning a task costs 1 reputation, a dispute 2 and a deadline 3
+	pub const AbandonPenalty: u32 = 1;
+	pub const DisputePenalty: u32 = 2;
+	pub const DeadlinePenalty: u32 = 3;
+	// Reputation never goes below 0 and every profile can take tasks
+	pub const ReputationFloor: u32 = 0;
+	pub static MinReputation: u32 = 0;
+	// Skills are at most 8 bytes and a profile has at most 3
+	pub const MaxSkillLength: u32 = 8;
+	pub const MaxSkills: u32 = 3;
+	// Endorsing stakes 2 reputation or reserves 5, a skill has at most 3 endorsements
+	pub const EndorsementReputation: u32 = 2;
+	pub const EndorsementDeposit: u64 = 5;
+	pub const MaxEndorsements: u32 = 3;
 }
 
 impl pallet_profile::Config for Test {
 	type Event = Event;
 	type Currency =  Balances;
+	type MaxUsernameLength = MaxUsernameLength;
+	type MaxInterestsLength = MaxInterestsLength;
+	type ReputationHandler = ();
+	type ReputationHalfLife = ReputationHalfLife;
+	type AbandonPenalty = AbandonPenalty;
+	type DisputePenalty = DisputePenalty;
+	type DeadlinePenalty = DeadlinePenalty;
+	type ReputationFloor = ReputationFloor;
+	type MinReputation = MinReputation;
+	type SkillRegistry = Task;
+	type MaxSkillLength = MaxSkillLength;
+	type MaxSkills = MaxSkills;
+	type EndorsementReputation = EndorsementReputation;
+	type EndorsementDeposit = EndorsementDeposit;
+	type MaxEndorsements = MaxEndorsements;
 }
 
 impl pallet_balances::Config for Test {
-	type ReserveIdentifier = [u8; 8];
+	type ReserveIdentifier = H256;
 	type MaxLocks = ();
-	type MaxReserves = ();
+	type MaxReserves = MaxReserves;
 	type Balance = u64;
 	type Event = Event;
 	type DustRemoval = ();
@@ -81,21 +121,140 @@ impl pallet_balances::Config for Test {
 }
 
 parameter_types! {
+	pub const AssetDeposit: u64 = 1;
+	pub const ApprovalDeposit: u64 = 1;
+	pub const StringLimit: u32 = 50;
+	pub const MetadataDepositBase: u64 = 1;
+	pub const MetadataDepositPerByte: u64 = 1;
+}
+
+impl pallet_assets::Config for Test {
+	type Event = Event;
+	type Balance = u64;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<u64>;
+	type AssetDeposit = AssetDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = StringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	// Titles are at most 8 bytes and specifications at most 64 bytes
+	pub const MaxTitleLength: u32 = 8;
+	pub const MaxSpecificationLength: u32 = 64;
 	// One can owned at most 77 tasks
 	pub const MaxTasksOwned: u32 = 77;
+	// At most 7 tasks can expire in the same block
+	pub const MaxDeadlinesPerBlock: u32 = 7;
+	pub const MinTaskBudget: u64 = 1;
+	// A task can have at most 3 applicants
+	pub const MaxApplicantsPerTask: u32 = 3;
+	// A task can have at most 3 milestones
+	pub const MaxMilestones: u32 = 3;
+	// A deliverable content identifier is at most 64 bytes
+	pub const MaxDeliverableLength: u32 = 64;
+	// At most 3 deliverable revisions are kept per task
+	pub const MaxRevisions: u32 = 3;
+	// At most 3 versions are kept per task
+	pub const MaxTaskVersions: u32 = 3;
+	// Tasks have at most 3 prerequisites, searched 4 levels deep
+	pub const MaxPrerequisites: u32 = 3;
+	pub const MaxDependencyDepth: u32 = 4;
+	// At most 3 tasks can depend on a task, and a task has at most 3 subtasks
+	pub const MaxDependents: u32 = 3;
+	pub const MaxSubtasks: u32 = 3;
+	// Asset budgets are escrowed by the task pallet account
+	pub const TaskPalletId: PalletId = PalletId(*b"py/tasks");
+	// Teams have at most 3 volunteers
+	pub const MaxTeamSize: u32 = 3;
+	// At most 3 accounts can fund a task
+	pub const MaxContributors: u32 = 3;
+	// Volunteers can be unassigned after 5 blocks without activity
+	pub const UnassignGracePeriod: u64 = 5;
+	// A skill tag is at most 8 bytes
+	pub const MaxTagLength: u32 = 8;
+	// A task can have at most 3 skill tags
+	pub const MaxTagsPerTask: u32 = 3;
+	// Raising a dispute reserves 5
+	pub const DisputeDeposit: u64 = 5;
+	// Arbitrators have 10 blocks to reach a ruling
+	pub const DisputeTimeout: u64 = 10;
+	// A dispute is resolved after 2 votes
+	pub const DisputeQuorum: u32 = 2;
+	// At most 3 arbitrators can vote on a dispute
+	pub const MaxArbitrators: u32 = 3;
+	// Closed tasks can be rated for 10 blocks
+	pub const RatingPeriod: u64 = 10;
+	// No commission is taken unless a test sets one
+	pub static Commission: Permill = Permill::zero();
+}
+
+// Accounts that can arbitrate any dispute
+pub struct Arbiters;
+impl SortedMembers<u64> for Arbiters {
+	fn sorted_members() -> Vec<u64> {
+		vec![20, 21, 22]
+	}
 }
 
 impl pallet_task::Config for Test {
 	type Event = Event;
 	type Currency = Balances;
+	type AssetId = u32;
+	type Assets = Assets;
+	type PalletId = TaskPalletId;
+	type MaxTitleLength = MaxTitleLength;
+	type MaxSpecificationLength = MaxSpecificationLength;
 	type MaxTasksOwned = MaxTasksOwned;
+	type MaxDeadlinesPerBlock = MaxDeadlinesPerBlock;
+	type MinTaskBudget = MinTaskBudget;
+	type MaxApplicantsPerTask = MaxApplicantsPerTask;
+	type MaxMilestones = MaxMilestones;
+	type MaxDeliverableLength = MaxDeliverableLength;
+	type MaxRevisions = MaxRevisions;
+	type MaxTaskVersions = MaxTaskVersions;
+	type MaxPrerequisites = MaxPrerequisites;
+	type MaxDependencyDepth = MaxDependencyDepth;
+	type MaxDependents = MaxDependents;
+	type MaxSubtasks = MaxSubtasks;
+	type MaxTeamSize = MaxTeamSize;
+	type MaxContributors = MaxContributors;
+	type UnassignGracePeriod = UnassignGracePeriod;
+	type TagOrigin = EnsureRoot<u64>;
+	type MaxTagLength = MaxTagLength;
+	type MaxTagsPerTask = MaxTagsPerTask;
+	type ArbitratorOrigin = EnsureSignedBy<Arbiters, u64>;
+	type Arbitrators = ();
+	type DisputeDeposit = DisputeDeposit;
+	type DisputeTimeout = DisputeTimeout;
+	type DisputeQuorum = DisputeQuorum;
+	type MaxArbitrators = MaxArbitrators;
+	type RatingPeriod = RatingPeriod;
+	type Commission = Commission;
+	type Treasury = ();
+	type Organizations = ();
 }
 
+// Asset that task budgets can be paid in besides the native currency.
+pub(crate) const ASSET_ID: u32 = 7;
+
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
 	GenesisConfig {
 		balances: BalancesConfig {
-			balances: vec![(1,  10), (2,  10)]
+			balances: vec![(1, 1000), (2, 1000), (3, 10), (10, 1000)]
+		},
+		// The asset is sufficient so the escrow account can hold it without a native balance
+		assets: AssetsConfig {
+			assets: vec![(ASSET_ID, 10, true, 1)],
+			metadata: vec![],
+			accounts: vec![(ASSET_ID, 1, 500), (ASSET_ID, 3, 50)],
 		},
 		..Default::default()
 	}
@@ -105,4 +264,12 @@ pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 	let mut ext = sp_io::TestExternalities::new(t);
 	ext.execute_with(|| System::set_block_number(1));
 	ext
-}
\ No newline at end of file
+}
+
+// Advance blocks and run the Task hooks until block `n` is reached.
+pub(crate) fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		Task::on_initialize(System::block_number());
+	}
+}
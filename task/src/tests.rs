@@ -1,9 +1,38 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
-
-pub const DEADLINE:u32 = 77;
+use crate::{mock::*, Error, TaskAction, TaskRole, TaskStatus};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult, storage::unhashed, traits::{GetStorageVersion, NamedReservableCurrency, ReservableCurrency, StorageVersion}, BoundedVec};
+use sp_std::convert::TryFrom;
+use pallet_profile::{PenaltyReason, StakeKind};
+use sp_core::H256;
+use sp_runtime::{traits::{BlakeTwo256, Hash}, DispatchError, Percent, Permill};
+
+pub const DEADLINE:u64 = 77;
 pub const USERNAME:[u8; 1] = [7];
 pub const TITLE:[u8; 1] = [1];  
+pub const DELIVERABLE:[u8; 4] = [7, 7, 7, 7];
+
+// Encodes an in progress task the way it was stored before storage version 1,
+// with a `u32` deadline and without tags
+fn legacy_task(title: Vec<u8>, initiator: u64, budget: u64, deadline: u32) -> Vec<u8> {
+	legacy_task_of(title, initiator, initiator, TaskStatus::InProgress, budget, deadline)
+}
+
+// Encodes a task with a volunteer and status the way it was stored before storage version 2.
+fn legacy_task_of(title: Vec<u8>, initiator: u64, volunteer: u64, status: TaskStatus, budget: u64, deadline: u32) -> Vec<u8> {
+	(title, Vec::<u8>::new(), initiator, volunteer, volunteer, status, budget, deadline).encode()
+}
+
+// Volunteer applies for the task and is assigned by the initiator.
+fn assign_volunteer(initiator: u64, volunteer: u64, hash: H256) {
+	assert_ok!(Task::apply_for_task(Origin::signed(volunteer), hash, None, H256::zero()));
+	assert_ok!(Task::assign_to_applicant(Origin::signed(initiator), hash, volunteer));
+}
+
+// Volunteer submits a deliverable and completes the task.
+fn complete_with_deliverable(volunteer: u64, hash: H256) {
+	assert_ok!(Task::submit_deliverable(Origin::signed(volunteer), hash, DELIVERABLE.to_vec()));
+	assert_ok!(Task::complete_task(Origin::signed(volunteer), hash));
+}
 
 #[test]
 fn create_new_task(){
@@ -16,7 +45,7 @@ fn create_new_task(){
 		vec.push(2);
 		
 		// Ensure new task can be created with [signer, specification, budget, deadline]
-		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec, 7, DEADLINE));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec, 7, DEADLINE, Vec::new()));
 	});
 }
 
@@ -31,7 +60,7 @@ fn increase_task_count_when_creating_task(){
 		vec.push(2);
 		
 		// Ensure new task can be created with [signer, specification, budget, deadline]
-		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec, 7, DEADLINE));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec, 7, DEADLINE, Vec::new()));
 
 		// Assert that count is incremented by 1 after task creation
 		assert_eq!(Task::task_count(), 1);
@@ -52,8 +81,8 @@ fn increase_task_count_when_creating_two_tasks(){
 		vec2.push(7);
 		
 		// Ensure new task can be created with [signer, specification, budget, deadline]
-		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE));
-		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec2, 99, DEADLINE));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE, Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec2, 99, DEADLINE, Vec::new()));
 
 		// Assert that count is incremented to 2 after task creation
 		assert_eq!(Task::task_count(), 2);
@@ -76,7 +105,7 @@ fn cant_own_more_tax_than_max_tasks(){
 			vec1.push(n);
 			
 			// Ensure new task can be created with [signer, specification, budget, deadline]
-			assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE));
+			assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE + n as u64, Vec::new()));
 		}	
 
 		// Assert that count is incremented to 2 after task creation
@@ -86,7 +115,7 @@ fn cant_own_more_tax_than_max_tasks(){
 		vec2.push(7);
 
 		// Assert that when creating the 77 Task, Error is thrown
-		assert_noop!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec2, 7, DEADLINE), Error::<Test>::ExceedMaxTasksOwned);
+		assert_noop!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec2, 7, DEADLINE, Vec::new()), Error::<Test>::ExceedMaxTasksOwned);
 
 	});
 
@@ -102,7 +131,7 @@ fn assign_task_to_current_owner(){
 		let mut vec1 = Vec::new();
 		vec1.push(2);
 
-		assert_ok!(Task::create_task(Origin::signed(10), TITLE.to_vec(), vec1, 7, DEADLINE));
+		assert_ok!(Task::create_task(Origin::signed(10), TITLE.to_vec(), vec1, 7, DEADLINE, Vec::new()));
 
 		// Get task through the hash
 		let hash = Task::tasks_owned(10)[0];
@@ -122,7 +151,7 @@ fn verify_inputs_outputs_to_tasks(){
 		let mut vec1 = Vec::new();
 		vec1.push(2);
 
-		assert_ok!(Task::create_task(Origin::signed(10), TITLE.to_vec(), vec1, 7, DEADLINE));
+		assert_ok!(Task::create_task(Origin::signed(10), TITLE.to_vec(), vec1, 7, DEADLINE, Vec::new()));
 
 		// Get task through the hash
 		let hash = Task::tasks_owned(10)[0];
@@ -131,7 +160,7 @@ fn verify_inputs_outputs_to_tasks(){
 		// Ensure that task properties are assigned correctly
 		assert_eq!(task.current_owner, 10);
 		assert_eq!(task.budget, 7);
-		assert_eq!(task.title, &[1]);
+		assert_eq!(task.title.to_vec(), &[1]);
 	});
 }
 
@@ -147,7 +176,7 @@ fn start_tasks_assigns_new_current_owner(){
 		vec1.push(2);
 
 		// Ensure new task can be created with [signer, specification, budget]
-		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE, Vec::new()));
 
 		// Ensure new task is assigned to new current_owner (user 1)
 		let hash = Task::tasks_owned(1)[0];
@@ -156,7 +185,7 @@ fn start_tasks_assigns_new_current_owner(){
 		assert_eq!(Task::tasks_owned(1).len(), 1);
 
 		// Ensure task is started by new current_owner (user 2)
-		assert_ok!(Task::start_task(Origin::signed(2), hash));
+		assign_volunteer(1, 2, hash);
 		
 		// Ensure when task is started user1 has 0 tasks, and user2 has 1
 		assert_eq!(Task::tasks_owned(1).len(), 0);
@@ -176,7 +205,7 @@ fn start_tasks_assigns_task_to_volunteer(){
 		vec1.push(2);
 
 		// Ensure new task can be created with [signer, specification, budget]
-		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE, Vec::new()));
 
 		// Ensure new task is assigned to new current_owner (user 1)
 		let hash = Task::tasks_owned(1)[0];
@@ -185,7 +214,7 @@ fn start_tasks_assigns_task_to_volunteer(){
 		assert_eq!(Task::tasks_owned(1).len(), 1);
 
 		// Ensure task is started by new current_owner (user 2)
-		assert_ok!(Task::start_task(Origin::signed(2), hash));
+		assign_volunteer(1, 2, hash);
 		
 		// Ensure when task is started it is assigned to volunteer (user 2)
 		assert_eq!(task.volunteer, 1);
@@ -205,7 +234,7 @@ fn completing_tasks_assigns_new_current_owner(){
 		vec1.push(2);
 
 		// Ensure new task can be created with [signer, specification, budget, deadline]
-		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE, Vec::new()));
 
 		// Ensure new task is assigned to new current_owner (user 1)
 		let hash = Task::tasks_owned(1)[0];
@@ -214,14 +243,14 @@ fn completing_tasks_assigns_new_current_owner(){
 		assert_eq!(Task::tasks_owned(1).len(), 1);
 
 		// Ensure task is started by new current_owner (user 2)
-		assert_ok!(Task::start_task(Origin::signed(2), hash));
+		assign_volunteer(1, 2, hash);
 		
 		// Ensure when task is started user1 has 0 tasks, and user2 has 1
 		assert_eq!(Task::tasks_owned(1).len(), 0);
 		assert_eq!(Task::tasks_owned(2).len(), 1);
 		
 		// Ensure task is completed by current current_owner (user 2)
-		assert_ok!(Task::complete_task(Origin::signed(2), hash));
+		complete_with_deliverable(2, hash);
 
 		// Ensure that the ownership is reversed again
 		assert_eq!(task.current_owner, 1);
@@ -242,7 +271,7 @@ fn only_creator_deletes_task(){
 		vec1.push(2);
 
 		// Ensure new task can be created with [signer, specification, budget]
-		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE, Vec::new()));
 
 		// Ensure new task is assigned to new current_owner (user 1)
 		let hash = Task::tasks_owned(1)[0];
@@ -251,19 +280,22 @@ fn only_creator_deletes_task(){
 		assert_eq!(Task::tasks_owned(1).len(), 1);
 
 		// Ensure task is started by new current_owner (user 2)
-		assert_ok!(Task::start_task(Origin::signed(2), hash));
+		assign_volunteer(1, 2, hash);
 		
 		// Ensure when task is started user1 has 0 tasks, and user2 has 1
 		assert_eq!(Task::tasks_owned(1).len(), 0);
 		assert_eq!(Task::tasks_owned(2).len(), 1);
 		
 		// Ensure task is completed by current current_owner (user 2)
-		assert_ok!(Task::complete_task(Origin::signed(2), hash));
+		complete_with_deliverable(2, hash);
 
 		// Ensure that the ownership is reversed again
 		assert_eq!(Task::tasks_owned(1).len(), 1);
 		assert_eq!(Task::tasks_owned(2).len(), 0);
 
+		// Ensure task is accepted by task creator (user 1)
+		assert_ok!(Task::accept_task(Origin::signed(1), hash, 0));
+
 		// Ensure task is removed by task creator (user 1)
 		assert_noop!(Task::remove_task(Origin::signed(2), hash), Error::<Test>::OnlyInitiatorClosesTask);
 		assert_ok!(Task::remove_task(Origin::signed(1), hash));
@@ -282,7 +314,7 @@ fn only_started_task_can_be_completed(){
 		vec1.push(2);
 
 		// Ensure new task can be created with [signer, specification, budget, deadline]
-		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE, Vec::new()));
 
 		// Ensure new task is assigned to new current_owner (user 1)
 		let hash = Task::tasks_owned(1)[0];
@@ -290,13 +322,13 @@ fn only_started_task_can_be_completed(){
 		assert_eq!(task.current_owner, 1);
 		assert_eq!(Task::tasks_owned(1).len(), 1);
 
-		assert_noop!(Task::complete_task(Origin::signed(2), hash), Error::<Test>::NoPermissionToComplete);
+		assert_noop!(Task::complete_task(Origin::signed(2), hash), Error::<Test>::TaskNotStarted);
 
 		// Ensure task is started by new current_owner (user 2)
-		assert_ok!(Task::start_task(Origin::signed(2), hash));
+		assign_volunteer(1, 2, hash);
 		
 		// Ensure task is completed by current current_owner (user 2)
-		assert_ok!(Task::complete_task(Origin::signed(2), hash));
+		complete_with_deliverable(2, hash);
 	});
 }
 
@@ -312,7 +344,7 @@ fn when_task_is_removed_ownership_is_cleared(){
 		vec1.push(2);
 
 		// Ensure new task can be created with [signer, specification, budget]
-		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE, Vec::new()));
 
 		// Ensure new task is assigned to new current_owner (user 1)
 		let hash = Task::tasks_owned(1)[0];
@@ -321,20 +353,21 @@ fn when_task_is_removed_ownership_is_cleared(){
 		assert_eq!(Task::tasks_owned(1).len(), 1);
 
 		// Ensure task is started by new current_owner (user 2)
-		assert_ok!(Task::start_task(Origin::signed(2), hash));
+		assign_volunteer(1, 2, hash);
 		
 		// Ensure when task is started user1 has 0 tasks, and user2 has 1
 		assert_eq!(Task::tasks_owned(1).len(), 0);
 		assert_eq!(Task::tasks_owned(2).len(), 1);
 		
 		// Ensure task is completed by current current_owner (user 2)
-		assert_ok!(Task::complete_task(Origin::signed(2), hash));
+		complete_with_deliverable(2, hash);
 
 		// Ensure that the ownership is reversed again
 		assert_eq!(Task::tasks_owned(1).len(), 1);
 		assert_eq!(Task::tasks_owned(2).len(), 0);
 
-		// Ensure task is removed by task creator (user 1)
+		// Ensure task is accepted and removed by task creator (user 1)
+		assert_ok!(Task::accept_task(Origin::signed(1), hash, 0));
 		assert_ok!(Task::remove_task(Origin::signed(1), hash));
 
 		// Ensure ownership of task is cleared
@@ -355,7 +388,7 @@ fn decrease_task_count_when_removing_task(){
 		vec.push(2);
 		
 		// Ensure new task can be created with [signer, specification, budget]
-		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec, 8, DEADLINE));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec, 8, DEADLINE, Vec::new()));
 
 		// Get hash of task owned
 		let hash = Task::tasks_owned(1)[0];
@@ -371,8 +404,179 @@ fn decrease_task_count_when_removing_task(){
 fn transfer_ballance_works(){
 	new_test_ext().execute_with( || {
 		
+		// Budget must be held in the escrow of the task before it can be transferred
+		assert_eq!(Task::transfer_balance(&H256::zero(), &1, &2, 7).map_err(DispatchError::from), Err(Error::<Test>::InsufficientReservedBalance.into()));
+		assert_ok!(Balances::reserve(&1, 7));
+		assert_eq!(Task::transfer_balance(&H256::zero(), &1, &2, 7).map_err(DispatchError::from), Err(Error::<Test>::InsufficientReservedBalance.into()));
+		assert_ok!(Balances::reserve_named(&H256::zero(), &1, 7));
+
 		// Transfer balance works using Mock
-		assert_ok!(Task::transfer_balance(&1, &2, 7));
+		assert_ok!(Task::transfer_balance(&H256::zero(), &1, &2, 7));
+		assert_eq!(Balances::reserved_balance(&1), 7);
+		assert_eq!(Balances::reserved_balance_named(&H256::zero(), &1), 0);
+		assert_eq!(Balances::free_balance(&2), 1007);
+	});
+}
+
+#[test]
+fn create_task_reserves_budget(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+
+		// Ensure new task can be created with [signer, specification, budget, deadline]
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+
+		// Ensure budget is moved from free to reserved balance
+		assert_eq!(Balances::free_balance(&1), 993);
+		assert_eq!(Balances::reserved_balance(&1), 7);
+	});
+}
+
+#[test]
+fn can_not_create_task_without_profile(){
+	new_test_ext().execute_with( || {
+
+		// Ensure task creation fails without panicking when there is no profile
+		assert_noop!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()), Error::<Test>::NoProfile);
+		assert_eq!(Task::task_count(), 0);
+	});
+}
+
+#[test]
+fn can_not_create_task_without_enough_balance(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(3), USERNAME.to_vec(), Vec::new()));
+
+		// Ensure task with budget higher than free balance can not be created
+		assert_noop!(Task::create_task(Origin::signed(3), TITLE.to_vec(), Vec::new(), 11, DEADLINE, Vec::new()), Error::<Test>::InsufficientBalanceToReserve);
+		assert_eq!(Task::task_count(), 0);
+		assert_eq!(Task::tasks_owned(3).len(), 0);
+	});
+}
+
+#[test]
+fn budget_can_not_be_double_spent_across_tasks(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(3), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+
+		// User 3 has balance of 10, first task reserves 6
+		assert_ok!(Task::create_task(Origin::signed(3), TITLE.to_vec(), [1].to_vec(), 6, DEADLINE, Vec::new()));
+
+		// Ensure the same funds can not be used for another task
+		assert_noop!(Task::create_task(Origin::signed(3), TITLE.to_vec(), [2].to_vec(), 6, DEADLINE, Vec::new()), Error::<Test>::InsufficientBalanceToReserve);
+
+		// Remaining funds can still be used for another task
+		assert_ok!(Task::create_task(Origin::signed(3), TITLE.to_vec(), [3].to_vec(), 4, DEADLINE, Vec::new()));
+		assert_eq!(Balances::free_balance(&3), 0);
+		assert_eq!(Balances::reserved_balance(&3), 10);
+
+		// Ensure both tasks can be completed and paid from escrow
+		let hash1 = Task::tasks_owned(3)[0];
+		let hash2 = Task::tasks_owned(3)[1];
+		for hash in [hash1, hash2] {
+			assign_volunteer(3, 2, hash);
+			complete_with_deliverable(2, hash);
+			assert_ok!(Task::accept_task(Origin::signed(3), hash, 0));
+		}
+
+		// Ensure volunteer was paid exactly the reserved budgets
+		assert_eq!(Balances::reserved_balance(&3), 0);
+		assert_eq!(Balances::free_balance(&3), 0);
+		assert_eq!(Balances::free_balance(&2), 1010);
+
+		// User 3 holds an endorsement deposit, a task escrow and a dispute deposit at once
+		Balances::make_free_balance_be(&3, 16);
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::register_tag(Origin::root(), b"rust".to_vec()));
+		assert_ok!(Profile::set_skills(Origin::signed(2), vec![(b"rust".to_vec(), 3)]));
+		assert_ok!(Profile::endorse_skill(Origin::signed(3), 2, b"rust".to_vec(), StakeKind::Deposit));
+
+		assert_ok!(Task::create_task(Origin::signed(3), TITLE.to_vec(), [4].to_vec(), 6, DEADLINE, Vec::new()));
+		let escrowed = *Task::tasks_owned(3).last().expect("should found the task");
+		assign_volunteer(3, 2, escrowed);
+
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), [5].to_vec(), 7, DEADLINE, Vec::new()));
+		let disputed = Task::tasks_owned(1)[0];
+		assign_volunteer(1, 3, disputed);
+		assert_ok!(Task::raise_dispute(Origin::signed(3), disputed));
+		assert_eq!(Balances::reserved_balance(&3), 16);
+
+		// Ensure a task can only be paid from its own escrow
+		assert_eq!(Task::transfer_balance(&escrowed, &3, &2, 7).map_err(DispatchError::from), Err(Error::<Test>::InsufficientReservedBalance.into()));
+		assert_eq!(Task::release_balance(&disputed, &3, 1).map_err(DispatchError::from), Err(Error::<Test>::InsufficientReservedBalance.into()));
+
+		// Ensure accepting the task leaves the deposits reserved
+		complete_with_deliverable(2, escrowed);
+		assert_ok!(Task::accept_task(Origin::signed(3), escrowed, 0));
+		assert_eq!(Balances::reserved_balance(&3), 10);
+		assert_eq!(Balances::free_balance(&2), 1016);
+
+		// Ensure the endorsement deposit is returned in full
+		assert_ok!(Profile::revoke_endorsement(Origin::signed(3), 2, b"rust".to_vec()));
+		assert_eq!(Balances::reserved_balance(&3), 5);
+		assert_eq!(Balances::free_balance(&3), 5);
+	});
+}
+
+#[test]
+fn accepting_task_pays_volunteer_from_escrow(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+
+		// Ensure new task can be created with [signer, specification, budget, deadline]
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+
+		// Ensure task is started and completed by volunteer (user 2)
+		assign_volunteer(1, 2, hash);
+		complete_with_deliverable(2, hash);
+
+		// Ensure task is accepted by task creator (user 1)
+		assert_ok!(Task::accept_task(Origin::signed(1), hash, 0));
+
+		// Ensure escrow was paid to the volunteer
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::free_balance(&1), 993);
+		assert_eq!(Balances::free_balance(&2), 1007);
+		System::assert_has_event(Event::Task(crate::Event::BudgetPaid(2, hash, 7)));
+
+		// Ensure removing the accepted task does not pay again
+		assert_ok!(Task::remove_task(Origin::signed(1), hash));
+		assert_eq!(Balances::free_balance(&1), 993);
+		assert_eq!(Balances::free_balance(&2), 1007);
+	});
+}
+
+#[test]
+fn removing_unfinished_task_unreserves_budget(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+
+		// Ensure new task can be created with [signer, specification, budget, deadline]
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+
+		// Ensure task is cancelled by task creator (user 1) before it is started
+		assert_ok!(Task::remove_task(Origin::signed(1), hash));
+
+		// Ensure escrow was returned to the initiator and volunteer was not paid
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::free_balance(&1), 1000);
+		assert_eq!(Balances::free_balance(&2), 1000);
+		System::assert_has_event(Event::Task(crate::Event::BudgetUnreserved(1, hash, 7)));
 	});
 }
 
@@ -392,7 +596,7 @@ fn increase_profile_reputation_when_task_completed(){
 		vec1.push(2);
 
 		// Ensure new task can be created with [signer, specification, budget]
-		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec1, 7, DEADLINE, Vec::new()));
 
 		// Ensure new task is assigned to new current_owner (user 1)
 		let hash = Task::tasks_owned(1)[0];
@@ -400,13 +604,13 @@ fn increase_profile_reputation_when_task_completed(){
 		assert_eq!(task.current_owner, 1);
 
 		// Ensure task is started by new current_owner (user 2)
-		assert_ok!(Task::start_task(Origin::signed(2), hash));
+		assign_volunteer(1, 2, hash);
 
 		// Ensure task is completed by current current_owner (user 2)
-		assert_ok!(Task::complete_task(Origin::signed(2), hash));
+		complete_with_deliverable(2, hash);
 
-		// Ensure task is removed by task creator (user 1)
-		assert_ok!(Task::remove_task(Origin::signed(1), hash));
+		// Ensure task is accepted by task creator (user 1)
+		assert_ok!(Task::accept_task(Origin::signed(1), hash, 0));
 
 		let profile1 = Profile::profiles(1).expect("should find the profile");
 		let profile2 = Profile::profiles(2).expect("should find the profile");
@@ -418,6 +622,26 @@ fn increase_profile_reputation_when_task_completed(){
 	});
 }
 
+#[test]
+fn task_is_accepted_after_initiator_removed_profile(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		assign_volunteer(1, 2, hash);
+		complete_with_deliverable(2, hash);
+
+		// Ensure only the volunteer collects reputation once the initiator has no profile
+		assert_ok!(Profile::remove_profile(Origin::signed(1)));
+		assert_ok!(Task::accept_task(Origin::signed(1), hash, 0));
+		assert!(Profile::profiles(1).is_none());
+		assert_eq!(Profile::profiles(2).expect("should find the profile").reputation, 1);
+	});
+}
+
 #[test]
 fn only_add_reputation_when_task_has_been_completed(){
 	new_test_ext().execute_with( || {
@@ -429,7 +653,7 @@ fn only_add_reputation_when_task_has_been_completed(){
 		vec.push(2);
 		
 		// Ensure new task can be created with [signer, specification, budget]
-		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec, 8, DEADLINE));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec, 8, DEADLINE, Vec::new()));
 
 		// Get hash of task owned
 		let hash = Task::tasks_owned(1)[0];
@@ -444,3 +668,1677 @@ fn only_add_reputation_when_task_has_been_completed(){
 		assert_eq!(profile.reputation, 0);
 	});
 }
+
+#[test]
+fn can_not_create_task_with_deadline_in_past(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+
+		run_to_block(10);
+
+		// Ensure deadline must be a future block
+		assert_noop!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, 5, Vec::new()), Error::<Test>::DeadlineInPast);
+		assert_noop!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, 10, Vec::new()), Error::<Test>::DeadlineInPast);
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, 11, Vec::new()));
+	});
+}
+
+#[test]
+fn can_not_exceed_max_deadlines_per_block(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+
+		// Create 7 tasks with the same deadline
+		for n in 0..7 {
+			assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), [n].to_vec(), 7, DEADLINE, Vec::new()));
+		}
+		assert_eq!(Task::expiring_tasks(DEADLINE).len(), 7);
+
+		// Ensure the 8th task in the same block is rejected
+		assert_noop!(Task::create_task(Origin::signed(1), TITLE.to_vec(), [7].to_vec(), 7, DEADLINE, Vec::new()), Error::<Test>::ExceedMaxDeadlinesPerBlock);
+	});
+}
+
+#[test]
+fn can_not_create_task_below_min_budget(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+
+		// Ensure deadline slots can not be taken by tasks without a budget
+		assert_noop!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 0, DEADLINE, Vec::new()), Error::<Test>::BudgetTooLow);
+		assert!(Task::expiring_tasks(DEADLINE).is_empty());
+
+		// Ensure the budget can not be lowered below the minimum either
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 1, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		assert_noop!(Task::update_task(Origin::signed(1), hash, TITLE.to_vec(), Vec::new(), 0, DEADLINE), Error::<Test>::BudgetTooLow);
+		assert_noop!(Task::apply_for_task(Origin::signed(2), hash, Some(0), H256::zero()), Error::<Test>::BudgetTooLow);
+		assert_ok!(Task::apply_for_task(Origin::signed(2), hash, Some(1), H256::zero()));
+	});
+}
+
+#[test]
+fn task_expires_when_deadline_is_reached(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		assign_volunteer(1, 2, hash);
+
+		// Ensure task is not expired before the deadline
+		run_to_block(DEADLINE - 1);
+		assert_eq!(Task::tasks(hash).expect("should found the task").status, TaskStatus::InProgress);
+
+		// Ensure task is expired at the deadline
+		run_to_block(DEADLINE);
+		let task = Task::tasks(hash).expect("should found the task");
+		assert_eq!(task.status, TaskStatus::Expired);
+		assert_eq!(task.current_owner, 1);
+		assert_eq!(Task::tasks_owned(1).len(), 1);
+		assert_eq!(Task::tasks_owned(2).len(), 0);
+		assert_eq!(Task::expiring_tasks(DEADLINE).len(), 0);
+
+		// Ensure escrow is returned to the initiator
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::free_balance(&1), 1000);
+		System::assert_last_event(Event::Task(crate::Event::TaskExpired(1, hash)));
+	});
+}
+
+#[test]
+fn submitted_task_does_not_expire(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		assign_volunteer(1, 2, hash);
+		complete_with_deliverable(2, hash);
+
+		run_to_block(DEADLINE);
+
+		// Ensure task stays in review and budget stays in escrow for the volunteer
+		assert_eq!(Task::tasks(hash).expect("should found the task").status, TaskStatus::InReview);
+		assert_eq!(Balances::reserved_balance(&1), 7);
+
+		// Ensure a task rejected after its deadline expires in the next block
+		assert_ok!(Task::reject_task(Origin::signed(1), hash, H256::zero()));
+		assert_eq!(Task::tasks(hash).expect("should found the task").deadline, DEADLINE + 1);
+		run_to_block(DEADLINE + 1);
+		assert_eq!(Task::tasks(hash).expect("should found the task").status, TaskStatus::Expired);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
+#[test]
+fn removing_expired_task_does_not_release_budget_twice(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), [1].to_vec(), 7, DEADLINE, Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), [2].to_vec(), 5, DEADLINE + 1, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+
+		run_to_block(DEADLINE);
+		assert_eq!(Balances::reserved_balance(&1), 5);
+
+		// Ensure only the expired task is removed and the other escrow is untouched
+		assert_ok!(Task::remove_task(Origin::signed(1), hash));
+		assert_eq!(Balances::reserved_balance(&1), 5);
+		assert_eq!(Balances::free_balance(&1), 995);
+		assert_eq!(Task::task_count(), 1);
+	});
+}
+
+#[test]
+fn completing_task_puts_it_in_review(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		assign_volunteer(1, 2, hash);
+		complete_with_deliverable(2, hash);
+
+		// Ensure task waits for the initiator and nobody is paid or rewarded yet
+		assert_eq!(Task::tasks(hash).expect("should found the task").status, TaskStatus::InReview);
+		assert_eq!(Balances::reserved_balance(&1), 7);
+		assert_eq!(Profile::profiles(2).expect("should find the profile").reputation, 0);
+
+		// Ensure task in review can not be removed
+		assert_noop!(Task::remove_task(Origin::signed(1), hash), Error::<Test>::TaskInReview);
+	});
+}
+
+#[test]
+fn only_initiator_accepts_task_in_review(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		assign_volunteer(1, 2, hash);
+
+		// Ensure task can not be accepted before it is submitted
+		assert_noop!(Task::accept_task(Origin::signed(1), hash, 0), Error::<Test>::TaskNotInReview);
+
+		complete_with_deliverable(2, hash);
+
+		// Ensure volunteer can not accept own work
+		assert_noop!(Task::accept_task(Origin::signed(2), hash, 0), Error::<Test>::OnlyInitiatorReviewsTask);
+		assert_ok!(Task::accept_task(Origin::signed(1), hash, 0));
+
+		// Ensure task is closed and can not be accepted twice
+		assert_eq!(Task::tasks(hash).expect("should found the task").status, TaskStatus::Closed);
+		assert_noop!(Task::accept_task(Origin::signed(1), hash, 0), Error::<Test>::TaskAlreadyClosed);
+		System::assert_last_event(Event::Task(crate::Event::TaskAccepted(1, hash)));
+	});
+}
+
+#[test]
+fn rejecting_task_returns_it_to_volunteer(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		assign_volunteer(1, 2, hash);
+		complete_with_deliverable(2, hash);
+
+		// Ensure only initiator can reject task
+		let reason = H256::repeat_byte(7);
+		assert_noop!(Task::reject_task(Origin::signed(2), hash, reason), Error::<Test>::OnlyInitiatorReviewsTask);
+		assert_ok!(Task::reject_task(Origin::signed(1), hash, reason));
+		System::assert_last_event(Event::Task(crate::Event::TaskRejected(1, hash, reason)));
+
+		// Ensure task is back in progress with the volunteer
+		let task = Task::tasks(hash).expect("should found the task");
+		assert_eq!(task.status, TaskStatus::InProgress);
+		assert_eq!(task.current_owner, 2);
+		assert_eq!(Task::tasks_owned(1).len(), 0);
+		assert_eq!(Task::tasks_owned(2).len(), 1);
+
+		// Ensure nobody is paid or rewarded for rejected work
+		assert_eq!(Balances::reserved_balance(&1), 7);
+		assert_eq!(Balances::free_balance(&2), 1000);
+		assert_eq!(Profile::profiles(2).expect("should find the profile").reputation, 0);
+
+		// Ensure task can be submitted again
+		complete_with_deliverable(2, hash);
+		assert_ok!(Task::accept_task(Origin::signed(1), hash, 1));
+		assert_eq!(Profile::profiles(2).expect("should find the profile").reputation, 1);
+	});
+}
+
+// Creates a task of user 1 and moves it into the given status with user 2 as volunteer.
+fn create_task_in_status(status: &TaskStatus) -> H256 {
+	assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+	assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+	assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+	let hash = Task::tasks_owned(1)[0];
+	assert_ok!(Task::apply_for_task(Origin::signed(2), hash, None, H256::zero()));
+
+	match status {
+		TaskStatus::Created => {},
+		TaskStatus::InProgress => {
+			assert_ok!(Task::assign_to_applicant(Origin::signed(1), hash, 2));
+		},
+		TaskStatus::InReview => {
+			assert_ok!(Task::assign_to_applicant(Origin::signed(1), hash, 2));
+			complete_with_deliverable(2, hash);
+		},
+		TaskStatus::Closed => {
+			assert_ok!(Task::assign_to_applicant(Origin::signed(1), hash, 2));
+			complete_with_deliverable(2, hash);
+			assert_ok!(Task::accept_task(Origin::signed(1), hash, 0));
+		},
+		TaskStatus::Expired => {
+			assert_ok!(Task::assign_to_applicant(Origin::signed(1), hash, 2));
+			run_to_block(DEADLINE);
+		},
+		TaskStatus::Disputed => {
+			assert_ok!(Task::assign_to_applicant(Origin::signed(1), hash, 2));
+			assert_ok!(Task::raise_dispute(Origin::signed(2), hash));
+		},
+	}
+	assert_eq!(Task::tasks(hash).expect("should found the task").status, *status);
+
+	hash
+}
+
+// Dispatches the extrinsic matching an action.
+fn dispatch_action(action: TaskAction, who: u64, hash: H256) -> DispatchResult {
+	match action {
+		TaskAction::Start => Task::assign_to_applicant(Origin::signed(who), hash, 2),
+		TaskAction::Complete => {
+			let _ = Task::submit_deliverable(Origin::signed(who), hash, DELIVERABLE.to_vec());
+			Task::complete_task(Origin::signed(who), hash)
+		},
+		TaskAction::Accept => Task::accept_task(Origin::signed(who), hash, 0),
+		TaskAction::Reject => Task::reject_task(Origin::signed(who), hash, H256::repeat_byte(1)),
+		TaskAction::Remove => Task::remove_task(Origin::signed(who), hash),
+		TaskAction::Dispute => Task::raise_dispute(Origin::signed(who), hash),
+		TaskAction::Abandon => Task::abandon_task(Origin::signed(who), hash),
+		TaskAction::Unassign => Task::unassign_task(Origin::signed(who), hash),
+		TaskAction::Fund => Task::fund_task(Origin::signed(who), hash, 1),
+		TaskAction::Update => Task::update_task(Origin::signed(who), hash, TITLE.to_vec(), [9].to_vec(), 7, DEADLINE + 1),
+		TaskAction::Expire => unreachable!("tasks are only expired by the runtime"),
+		TaskAction::Resolve => unreachable!("disputes are only resolved by arbitrators"),
+		TaskAction::ApproveMilestone => unreachable!("milestones are approved on tasks with milestones"),
+	}
+}
+
+#[test]
+fn every_status_role_and_action_follows_transition_table(){
+	use TaskStatus::*;
+
+	// Initiator is user 1, volunteer is user 2 (once started), user 3 is unrelated
+	const INITIATOR: u64 = 1;
+	const VOLUNTEER: u64 = 2;
+	const OTHER: u64 = 3;
+
+	let statuses = [Created, InProgress, InReview, Closed, Expired, Disputed];
+	let actions = [TaskAction::Start, TaskAction::Complete, TaskAction::Accept, TaskAction::Reject, TaskAction::Remove, TaskAction::Dispute, TaskAction::Update,
+		TaskAction::Abandon, TaskAction::Unassign, TaskAction::Fund];
+	let accounts = [INITIATOR, VOLUNTEER, OTHER];
+
+	for status in statuses.iter() {
+		for action in actions.iter() {
+			for who in accounts.iter() {
+				let expected: Result<(), Error<Test>> = match (status, action, *who) {
+					(Created, TaskAction::Start, INITIATOR) => Ok(()),
+					(Created, TaskAction::Start, _) => Err(Error::<Test>::OnlyInitiatorAssignsTask),
+					(Created, TaskAction::Complete, _) => Err(Error::<Test>::TaskNotStarted),
+					(Created, TaskAction::Accept, _) | (Created, TaskAction::Reject, _) => Err(Error::<Test>::TaskNotInReview),
+					(Created, TaskAction::Remove, INITIATOR) => Ok(()),
+					(Created, TaskAction::Remove, _) => Err(Error::<Test>::OnlyInitiatorClosesTask),
+					(Created, TaskAction::Dispute, _) => Err(Error::<Test>::TaskNotStarted),
+					(Created, TaskAction::Update, INITIATOR) => Ok(()),
+					(Created, TaskAction::Update, _) => Err(Error::<Test>::OnlyInitiatorUpdatesTask),
+					(Created, TaskAction::Abandon, _) | (Created, TaskAction::Unassign, _) => Err(Error::<Test>::TaskNotStarted),
+					(Created, TaskAction::Fund, _) => Ok(()),
+
+					(InProgress, TaskAction::Start, _) | (InProgress, TaskAction::Update, _) => Err(Error::<Test>::TaskAlreadyStarted),
+					(InProgress, TaskAction::Complete, VOLUNTEER) => Ok(()),
+					(InProgress, TaskAction::Complete, _) => Err(Error::<Test>::NoPermissionToComplete),
+					(InProgress, TaskAction::Accept, _) | (InProgress, TaskAction::Reject, _) => Err(Error::<Test>::TaskNotInReview),
+					(InProgress, TaskAction::Remove, _) => Err(Error::<Test>::TaskInProgress),
+					(InProgress, TaskAction::Dispute, INITIATOR) | (InProgress, TaskAction::Dispute, VOLUNTEER) => Ok(()),
+					(InProgress, TaskAction::Dispute, _) => Err(Error::<Test>::NotTaskParty),
+					(InProgress, TaskAction::Abandon, VOLUNTEER) | (InProgress, TaskAction::Unassign, INITIATOR) => Ok(()),
+					(InProgress, TaskAction::Abandon, _) => Err(Error::<Test>::OnlyVolunteerAbandonsTask),
+					(InProgress, TaskAction::Unassign, _) => Err(Error::<Test>::OnlyInitiatorUnassignsTask),
+					(InProgress, TaskAction::Fund, _) => Ok(()),
+
+					(InReview, TaskAction::Start, _) | (InReview, TaskAction::Update, _) => Err(Error::<Test>::TaskAlreadyStarted),
+					(InReview, TaskAction::Complete, _) => Err(Error::<Test>::TaskAlreadySubmitted),
+					(InReview, TaskAction::Accept, INITIATOR) | (InReview, TaskAction::Reject, INITIATOR) => Ok(()),
+					(InReview, TaskAction::Accept, _) | (InReview, TaskAction::Reject, _) => Err(Error::<Test>::OnlyInitiatorReviewsTask),
+					(InReview, TaskAction::Remove, _) => Err(Error::<Test>::TaskInReview),
+					(InReview, TaskAction::Dispute, INITIATOR) | (InReview, TaskAction::Dispute, VOLUNTEER) => Ok(()),
+					(InReview, TaskAction::Dispute, _) => Err(Error::<Test>::NotTaskParty),
+					(InReview, TaskAction::Abandon, _) | (InReview, TaskAction::Unassign, _) | (InReview, TaskAction::Fund, _) => Err(Error::<Test>::TaskInReview),
+
+					(Closed, TaskAction::Remove, INITIATOR) => Ok(()),
+					(Closed, TaskAction::Remove, _) => Err(Error::<Test>::OnlyInitiatorClosesTask),
+					(Closed, _, _) => Err(Error::<Test>::TaskAlreadyClosed),
+
+					(Expired, TaskAction::Remove, INITIATOR) => Ok(()),
+					(Expired, TaskAction::Remove, _) => Err(Error::<Test>::OnlyInitiatorClosesTask),
+					(Expired, _, _) => Err(Error::<Test>::TaskAlreadyExpired),
+
+					(Disputed, _, _) => Err(Error::<Test>::TaskDisputed),
+
+					_ => unreachable!(),
+				};
+
+				new_test_ext().execute_with( || {
+					let hash = create_task_in_status(status);
+
+					// Let the unassign grace period pass
+					run_to_block(System::block_number() + 5);
+					match expected {
+						Ok(()) => assert_ok!(dispatch_action(*action, *who, hash)),
+						Err(error) => assert_noop!(dispatch_action(*action, *who, hash), error),
+					}
+				});
+			}
+		}
+	}
+}
+
+#[test]
+fn only_open_tasks_can_be_expired_by_system(){
+	use TaskStatus::*;
+
+	let roles = [TaskRole::Initiator, TaskRole::Volunteer, TaskRole::Other, TaskRole::System];
+
+	for role in roles.iter() {
+		let expected = |status: TaskStatus| -> Result<TaskStatus, DispatchError> {
+			match (status, *role) {
+				(Created, TaskRole::System) | (InProgress, TaskRole::System) => Ok(Expired),
+				(Created, _) | (InProgress, _) => Err(Error::<Test>::OnlySystemExpiresTask.into()),
+				(InReview, _) => Err(Error::<Test>::TaskInReview.into()),
+				(Closed, _) => Err(Error::<Test>::TaskAlreadyClosed.into()),
+				(Expired, _) => Err(Error::<Test>::TaskAlreadyExpired.into()),
+				(Disputed, _) => Err(Error::<Test>::TaskDisputed.into()),
+			}
+		};
+
+		for status in [Created, InProgress, InReview, Closed, Expired, Disputed].iter() {
+			let result = status.transition::<Test>(TaskAction::Expire, *role).map_err(DispatchError::from);
+			assert_eq!(result, expected(status.clone()));
+		}
+	}
+}
+
+#[test]
+fn only_initiator_approves_milestones_of_started_tasks(){
+	use TaskStatus::*;
+
+	let roles = [TaskRole::Initiator, TaskRole::Volunteer, TaskRole::Other, TaskRole::System];
+
+	for role in roles.iter() {
+		let expected = |status: TaskStatus| -> Result<TaskStatus, DispatchError> {
+			match (status.clone(), *role) {
+				(InProgress, TaskRole::Initiator) | (InReview, TaskRole::Initiator) => Ok(status),
+				(InProgress, _) | (InReview, _) => Err(Error::<Test>::OnlyInitiatorReviewsTask.into()),
+				(Created, _) => Err(Error::<Test>::TaskNotStarted.into()),
+				(Closed, _) => Err(Error::<Test>::TaskAlreadyClosed.into()),
+				(Expired, _) => Err(Error::<Test>::TaskAlreadyExpired.into()),
+				(Disputed, _) => Err(Error::<Test>::TaskDisputed.into()),
+			}
+		};
+
+		for status in [Created, InProgress, InReview, Closed, Expired, Disputed].iter() {
+			let result = status.transition::<Test>(TaskAction::ApproveMilestone, *role).map_err(DispatchError::from);
+			assert_eq!(result, expected(status.clone()));
+		}
+	}
+}
+
+#[test]
+fn volunteer_can_apply_for_task(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+
+		// Ensure volunteer can apply with a counter-offer and a pitch
+		let pitch = H256::repeat_byte(2);
+		assert_ok!(Task::apply_for_task(Origin::signed(2), hash, Some(9), pitch));
+		System::assert_last_event(Event::Task(crate::Event::TaskApplied(2, hash)));
+
+		// Ensure the initiator can list the applicants
+		let applications = Task::applications(hash);
+		assert_eq!(applications.len(), 1);
+		assert_eq!(applications[0].applicant, 2);
+		assert_eq!(applications[0].price, Some(9));
+		assert_eq!(applications[0].pitch, pitch);
+
+		// Ensure task is not assigned by applying
+		let task = Task::tasks(hash).expect("should found the task");
+		assert_eq!(task.status, TaskStatus::Created);
+		assert_eq!(task.current_owner, 1);
+	});
+}
+
+#[test]
+fn invalid_applications_are_rejected(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+
+		// Ensure initiator can not apply for own task
+		assert_noop!(Task::apply_for_task(Origin::signed(1), hash, None, H256::zero()), Error::<Test>::InitiatorCannotApply);
+
+		// Ensure volunteer can apply only once
+		assert_ok!(Task::apply_for_task(Origin::signed(2), hash, None, H256::zero()));
+		assert_noop!(Task::apply_for_task(Origin::signed(2), hash, None, H256::zero()), Error::<Test>::AlreadyApplied);
+
+		// Ensure application list is bounded
+		assert_ok!(Task::apply_for_task(Origin::signed(3), hash, None, H256::zero()));
+		assert_ok!(Task::apply_for_task(Origin::signed(4), hash, None, H256::zero()));
+		assert_noop!(Task::apply_for_task(Origin::signed(5), hash, None, H256::zero()), Error::<Test>::ExceedMaxApplicants);
+
+		// Ensure started tasks do not accept applications
+		assert_ok!(Task::assign_to_applicant(Origin::signed(1), hash, 2));
+		assert_noop!(Task::apply_for_task(Origin::signed(5), hash, None, H256::zero()), Error::<Test>::TaskNotOpenForApplications);
+	});
+}
+
+#[test]
+fn applicant_can_withdraw_application(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+
+		assert_ok!(Task::apply_for_task(Origin::signed(2), hash, None, H256::zero()));
+		assert_ok!(Task::apply_for_task(Origin::signed(3), hash, None, H256::zero()));
+
+		// Ensure only applicants can withdraw
+		assert_noop!(Task::withdraw_application(Origin::signed(4), hash), Error::<Test>::NotApplicant);
+		assert_ok!(Task::withdraw_application(Origin::signed(2), hash));
+		System::assert_last_event(Event::Task(crate::Event::ApplicationWithdrawn(2, hash)));
+		assert_eq!(Task::applications(hash).len(), 1);
+		assert_eq!(Task::applications(hash)[0].applicant, 3);
+
+		// Ensure withdrawn applicant can not be assigned
+		assert_noop!(Task::assign_to_applicant(Origin::signed(1), hash, 2), Error::<Test>::NotApplicant);
+	});
+}
+
+#[test]
+fn only_initiator_assigns_task_to_applicant(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+
+		assert_ok!(Task::apply_for_task(Origin::signed(2), hash, None, H256::zero()));
+		assert_ok!(Task::apply_for_task(Origin::signed(3), hash, None, H256::zero()));
+
+		// Ensure applicants can not assign themselves
+		assert_noop!(Task::assign_to_applicant(Origin::signed(2), hash, 2), Error::<Test>::OnlyInitiatorAssignsTask);
+
+		// Ensure initiator can not assign an account that did not apply
+		assert_noop!(Task::assign_to_applicant(Origin::signed(1), hash, 4), Error::<Test>::NotApplicant);
+
+		// Ensure initiator picks an applicant and applications are cleaned up
+		assert_ok!(Task::assign_to_applicant(Origin::signed(1), hash, 3));
+		System::assert_last_event(Event::Task(crate::Event::TaskAssigned(3, hash)));
+		let task = Task::tasks(hash).expect("should found the task");
+		assert_eq!(task.volunteer, 3);
+		assert_eq!(task.status, TaskStatus::InProgress);
+		assert_eq!(Task::applications(hash).len(), 0);
+
+		// Ensure an assigned task can not be re-assigned
+		assert_noop!(Task::assign_to_applicant(Origin::signed(1), hash, 2), Error::<Test>::TaskAlreadyStarted);
+	});
+}
+
+#[test]
+fn removing_task_clears_applications(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+
+		assert_ok!(Task::apply_for_task(Origin::signed(2), hash, None, H256::zero()));
+		assert_ok!(Task::remove_task(Origin::signed(1), hash));
+
+		assert_eq!(Task::applications(hash).len(), 0);
+	});
+}
+
+#[test]
+fn counter_offer_adjusts_escrowed_budget(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), [1].to_vec(), 7, DEADLINE, Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), [2].to_vec(), 7, DEADLINE, Vec::new()));
+		let hash1 = Task::tasks_owned(1)[0];
+		let hash2 = Task::tasks_owned(1)[1];
+
+		// Ensure higher counter-offer reserves the difference
+		assert_ok!(Task::apply_for_task(Origin::signed(2), hash1, Some(10), H256::zero()));
+		assert_ok!(Task::assign_to_applicant(Origin::signed(1), hash1, 2));
+		assert_eq!(Task::tasks(hash1).expect("should found the task").budget, 10);
+		assert_eq!(Balances::reserved_balance(&1), 17);
+
+		// Ensure lower counter-offer releases the difference
+		assert_ok!(Task::apply_for_task(Origin::signed(3), hash2, Some(4), H256::zero()));
+		assert_ok!(Task::assign_to_applicant(Origin::signed(1), hash2, 3));
+		assert_eq!(Task::tasks(hash2).expect("should found the task").budget, 4);
+		assert_eq!(Balances::reserved_balance(&1), 14);
+	});
+}
+
+#[test]
+fn counter_offer_above_initiator_funds_can_not_be_assigned(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(3), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(3), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(3)[0];
+
+		// User 3 only has 10 in total
+		assert_ok!(Task::apply_for_task(Origin::signed(2), hash, Some(11), H256::zero()));
+		assert_noop!(Task::assign_to_applicant(Origin::signed(3), hash, 2), Error::<Test>::InsufficientBalanceToReserve);
+	});
+}
+
+fn create_milestone_task(shares: &[u8]) -> H256 {
+	let milestones = shares.iter().map(|share| (H256::zero(), Percent::from_percent(*share))).collect();
+	assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+	assert_ok!(Task::create_task_with_milestones(Origin::signed(1), TITLE.to_vec(), Vec::new(), 100, DEADLINE, Vec::new(), milestones));
+	Task::tasks_owned(1)[0]
+}
+
+#[test]
+fn milestone_shares_must_cover_whole_budget(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+
+		let short = vec![(H256::zero(), Percent::from_percent(40)), (H256::zero(), Percent::from_percent(50))];
+		assert_noop!(Task::create_task_with_milestones(Origin::signed(1), TITLE.to_vec(), Vec::new(), 100, DEADLINE, Vec::new(), short), Error::<Test>::InvalidMilestoneShares);
+
+		let empty_share = vec![(H256::zero(), Percent::from_percent(100)), (H256::zero(), Percent::from_percent(0))];
+		assert_noop!(Task::create_task_with_milestones(Origin::signed(1), TITLE.to_vec(), Vec::new(), 100, DEADLINE, Vec::new(), empty_share), Error::<Test>::InvalidMilestoneShares);
+
+		let too_many = vec![(H256::zero(), Percent::from_percent(25)); 4];
+		assert_noop!(Task::create_task_with_milestones(Origin::signed(1), TITLE.to_vec(), Vec::new(), 100, DEADLINE, Vec::new(), too_many), Error::<Test>::ExceedMaxMilestones);
+	});
+}
+
+#[test]
+fn approving_milestones_pays_shares_of_budget(){
+	new_test_ext().execute_with( || {
+
+		let hash = create_milestone_task(&[30, 70]);
+		assign_volunteer(1, 2, hash);
+
+		// Milestones with separate completion and acceptance are not allowed
+		assert_noop!(Task::complete_task(Origin::signed(2), hash), Error::<Test>::TaskHasMilestones);
+
+		// Ensure first milestone pays its share and keeps the task in progress
+		assert_ok!(Task::submit_milestone(Origin::signed(2), hash, 0));
+		assert_ok!(Task::approve_milestone(Origin::signed(1), hash, 0));
+		assert_eq!(Balances::free_balance(&2), 1030);
+		assert_eq!(Balances::reserved_balance(&1), 70);
+		assert_eq!(Task::tasks(hash).expect("should found the task").status, TaskStatus::InProgress);
+
+		// Ensure last milestone pays the remainder and closes the task
+		assert_ok!(Task::submit_milestone(Origin::signed(2), hash, 1));
+		assert_eq!(Task::tasks(hash).expect("should found the task").status, TaskStatus::InReview);
+		assert_ok!(Task::approve_milestone(Origin::signed(1), hash, 1));
+		assert_eq!(Balances::free_balance(&2), 1100);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Task::tasks(hash).expect("should found the task").status, TaskStatus::Closed);
+	});
+}
+
+#[test]
+fn milestones_are_submitted_and_approved_in_order(){
+	new_test_ext().execute_with( || {
+
+		let hash = create_milestone_task(&[30, 30, 40]);
+		assign_volunteer(1, 2, hash);
+
+		assert_noop!(Task::submit_milestone(Origin::signed(2), hash, 1), Error::<Test>::MilestoneOutOfOrder);
+		assert_noop!(Task::submit_milestone(Origin::signed(1), hash, 0), Error::<Test>::NoPermissionToComplete);
+		assert_noop!(Task::approve_milestone(Origin::signed(1), hash, 0), Error::<Test>::MilestoneNotSubmitted);
+
+		assert_ok!(Task::submit_milestone(Origin::signed(2), hash, 0));
+		assert_noop!(Task::submit_milestone(Origin::signed(2), hash, 0), Error::<Test>::MilestoneAlreadySubmitted);
+		assert_noop!(Task::approve_milestone(Origin::signed(2), hash, 0), Error::<Test>::OnlyInitiatorReviewsTask);
+		assert_noop!(Task::approve_milestone(Origin::signed(1), hash, 3), Error::<Test>::MilestoneNotExist);
+	});
+}
+
+#[test]
+fn expired_milestone_task_returns_only_unpaid_budget(){
+	new_test_ext().execute_with( || {
+
+		let hash = create_milestone_task(&[30, 70]);
+		assign_volunteer(1, 2, hash);
+		assert_ok!(Task::submit_milestone(Origin::signed(2), hash, 0));
+		assert_ok!(Task::approve_milestone(Origin::signed(1), hash, 0));
+
+		run_to_block(DEADLINE);
+
+		assert_eq!(Task::tasks(hash).expect("should found the task").status, TaskStatus::Expired);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::free_balance(&1), 970);
+		assert_eq!(Balances::free_balance(&2), 1030);
+	});
+}
+
+fn create_disputed_task(raised_by: u64) -> H256 {
+	assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+	assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 100, DEADLINE, Vec::new()));
+	let hash = Task::tasks_owned(1)[0];
+	assign_volunteer(1, 2, hash);
+	assert_ok!(Task::raise_dispute(Origin::signed(raised_by), hash));
+	hash
+}
+
+#[test]
+fn raising_dispute_freezes_escrow(){
+	new_test_ext().execute_with( || {
+
+		let hash = create_disputed_task(2);
+
+		// Ensure the deposit is reserved and the budget stays in escrow
+		assert_eq!(Balances::reserved_balance(&2), 5);
+		assert_eq!(Balances::reserved_balance(&1), 100);
+		assert_eq!(Task::tasks(hash).expect("should found the task").status, TaskStatus::Disputed);
+		assert_eq!(Task::disputes(hash).expect("should found the dispute").raised_by, 2);
+
+		// Ensure the task can not be settled while disputed
+		assert_noop!(Task::complete_task(Origin::signed(2), hash), Error::<Test>::TaskDisputed);
+		assert_noop!(Task::remove_task(Origin::signed(1), hash), Error::<Test>::TaskDisputed);
+		assert_noop!(Task::raise_dispute(Origin::signed(1), hash), Error::<Test>::TaskDisputed);
+	});
+}
+
+#[test]
+fn arbitrators_split_budget_by_median_vote(){
+	new_test_ext().execute_with( || {
+
+		let hash = create_disputed_task(2);
+
+		assert_ok!(Task::vote_on_dispute(Origin::signed(20), hash, Percent::from_percent(40)));
+		assert_eq!(Task::tasks(hash).expect("should found the task").status, TaskStatus::Disputed);
+		assert_ok!(Task::vote_on_dispute(Origin::signed(21), hash, Percent::from_percent(60)));
+
+		// Ensure the volunteer is paid the ruling and the rest returns to the initiator
+		assert_eq!(Task::tasks(hash).expect("should found the task").status, TaskStatus::Closed);
+		assert_eq!(Task::tasks_owned(1).len(), 1);
+		assert_eq!(Balances::free_balance(&2), 1040);
+		assert_eq!(Balances::free_balance(&1), 960);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert!(Task::disputes(hash).is_none());
+	});
+}
+
+#[test]
+fn only_arbitrators_vote_once_on_dispute(){
+	new_test_ext().execute_with( || {
+
+		let hash = create_disputed_task(1);
+
+		assert_noop!(Task::vote_on_dispute(Origin::signed(3), hash, Percent::from_percent(50)), Error::<Test>::NotArbitrator);
+		assert_noop!(Task::vote_on_dispute(Origin::signed(1), hash, Percent::from_percent(50)), Error::<Test>::NotArbitrator);
+
+		assert_ok!(Task::vote_on_dispute(Origin::signed(20), hash, Percent::from_percent(50)));
+		assert_noop!(Task::vote_on_dispute(Origin::signed(20), hash, Percent::from_percent(0)), Error::<Test>::AlreadyVoted);
+	});
+}
+
+#[test]
+fn frivolous_dispute_deposit_is_slashed(){
+	new_test_ext().execute_with( || {
+
+		let hash = create_disputed_task(2);
+
+		assert_ok!(Task::vote_on_dispute(Origin::signed(20), hash, Percent::from_percent(0)));
+		assert_ok!(Task::vote_on_dispute(Origin::signed(21), hash, Percent::from_percent(0)));
+
+		// Ensure the volunteer loses the deposit and the initiator gets the budget back
+		assert_eq!(Balances::free_balance(&2), 995);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(Balances::free_balance(&1), 1000);
+	});
+}
+
+#[test]
+fn dispute_times_out_without_ruling(){
+	new_test_ext().execute_with( || {
+
+		let hash = create_disputed_task(1);
+
+		// Ensure the budget is split evenly when no arbitrator voted
+		run_to_block(System::block_number() + 10);
+		assert_eq!(Task::tasks(hash).expect("should found the task").status, TaskStatus::Closed);
+		assert_eq!(Balances::free_balance(&2), 1050);
+		assert_eq!(Balances::free_balance(&1), 950);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
+#[test]
+fn dispute_that_can_not_be_resolved_times_out_again(){
+	new_test_ext().execute_with( || {
+
+		let hash = create_disputed_task(1);
+
+		// Ensure a dispute whose escrow can't be paid out is rescheduled
+		let escrow = Balances::reserved_balance_named(&hash, &1);
+		Balances::unreserve_named(&hash, &1, escrow);
+		let timeout = System::block_number() + 10;
+		run_to_block(timeout);
+		assert_eq!(Task::tasks(hash).expect("should found the task").status, TaskStatus::Disputed);
+		assert_eq!(Task::disputes(hash).expect("should find the dispute").timeout, timeout + 10);
+		assert_eq!(Task::dispute_timeouts(timeout + 10).to_vec(), vec![hash]);
+
+		// Ensure it is resolved once the escrow can be paid out
+		assert_ok!(Balances::reserve_named(&hash, &1, escrow));
+		run_to_block(timeout + 10);
+		assert_eq!(Task::tasks(hash).expect("should found the task").status, TaskStatus::Closed);
+		assert_eq!(Task::disputes(hash), None);
+	});
+}
+
+#[test]
+fn volunteer_submits_bounded_deliverable_history(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+
+		// Ensure only the volunteer of a started task submits deliverables
+		assert_noop!(Task::submit_deliverable(Origin::signed(2), hash, DELIVERABLE.to_vec()), Error::<Test>::TaskNotStarted);
+		assign_volunteer(1, 2, hash);
+		assert_noop!(Task::submit_deliverable(Origin::signed(1), hash, DELIVERABLE.to_vec()), Error::<Test>::NoPermissionToComplete);
+		assert_noop!(Task::submit_deliverable(Origin::signed(2), hash, [0; 65].to_vec()), Error::<Test>::DeliverableTooLong);
+
+		// Ensure task can not be completed without a deliverable
+		assert_noop!(Task::complete_task(Origin::signed(2), hash), Error::<Test>::NoDeliverable);
+
+		// Ensure only the latest revisions are kept
+		for revision in 0..4u8 {
+			assert_ok!(Task::submit_deliverable(Origin::signed(2), hash, [revision].to_vec()));
+		}
+		let deliverables = Task::deliverables(hash);
+		assert_eq!(deliverables.len(), 3);
+		assert_eq!(deliverables[0].revision, 1);
+		assert_eq!(deliverables[2].revision, 3);
+		assert_eq!(deliverables[2].content.to_vec(), [3].to_vec());
+		assert_eq!(deliverables[2].submitted_at, System::block_number());
+	});
+}
+
+#[test]
+fn acceptance_and_disputes_refer_to_latest_deliverable(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), [1].to_vec(), 7, DEADLINE, Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), [2].to_vec(), 7, DEADLINE, Vec::new()));
+		let hash1 = Task::tasks_owned(1)[0];
+		let hash2 = Task::tasks_owned(1)[1];
+
+		// Ensure an outdated revision can not be accepted
+		assign_volunteer(1, 2, hash1);
+		assert_ok!(Task::submit_deliverable(Origin::signed(2), hash1, [1].to_vec()));
+		complete_with_deliverable(2, hash1);
+		assert_noop!(Task::accept_task(Origin::signed(1), hash1, 0), Error::<Test>::DeliverableNotLatest);
+		assert_ok!(Task::accept_task(Origin::signed(1), hash1, 1));
+		System::assert_has_event(Event::Task(crate::Event::DeliverableAccepted(1, hash1, 1)));
+
+		// Ensure a dispute records the deliverable it is about
+		assign_volunteer(1, 2, hash2);
+		complete_with_deliverable(2, hash2);
+		assert_ok!(Task::raise_dispute(Origin::signed(1), hash2));
+		assert_eq!(Task::disputes(hash2).expect("should found the dispute").deliverable, Some(0));
+	});
+}
+
+#[test]
+fn only_governance_manages_tag_registry(){
+	new_test_ext().execute_with( || {
+
+		assert_noop!(Task::register_tag(Origin::signed(1), b"rust".to_vec()), DispatchError::BadOrigin);
+		assert_ok!(Task::register_tag(Origin::root(), b"rust".to_vec()));
+		assert_noop!(Task::register_tag(Origin::root(), b"rust".to_vec()), Error::<Test>::TagAlreadyExists);
+		assert_noop!(Task::register_tag(Origin::root(), b"substrate".to_vec()), Error::<Test>::TagTooLong);
+
+		assert_noop!(Task::unregister_tag(Origin::signed(1), b"rust".to_vec()), DispatchError::BadOrigin);
+		assert_ok!(Task::unregister_tag(Origin::root(), b"rust".to_vec()));
+		assert_noop!(Task::unregister_tag(Origin::root(), b"rust".to_vec()), Error::<Test>::UnknownTag);
+	});
+}
+
+#[test]
+fn applicants_are_ranked_by_endorsed_skills(){
+	new_test_ext().execute_with( || {
+
+		// Profiles draw their skills from the tag registry
+		assert_ok!(Task::register_tag(Origin::root(), b"rust".to_vec()));
+		for account in 1..=3 {
+			assert_ok!(Profile::create_profile(Origin::signed(account), USERNAME.to_vec(), Vec::new()));
+		}
+		assert_noop!(Profile::set_skills(Origin::signed(2), vec![(b"design".to_vec(), 3)]), pallet_profile::Error::<Test>::SkillNotRegistered);
+		assert_ok!(Profile::set_skills(Origin::signed(2), vec![(b"rust".to_vec(), 5)]));
+		assert_ok!(Profile::set_skills(Origin::signed(3), vec![(b"rust".to_vec(), 2)]));
+		assert_ok!(Profile::endorse_skill(Origin::signed(1), 3, b"rust".to_vec(), StakeKind::Deposit));
+
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, vec![b"rust".to_vec()]));
+		let hash = Task::tasks_owned(1)[0];
+		assert_ok!(Task::apply_for_task(Origin::signed(2), hash, None, H256::zero()));
+		assert_ok!(Task::apply_for_task(Origin::signed(3), hash, None, H256::zero()));
+
+		// Ensure endorsed applicants come first, whatever the self-declared level
+		assert_eq!(Task::ranked_applicants(hash), vec![(3, 1), (2, 0)]);
+	});
+}
+
+#[test]
+fn tasks_can_only_use_registered_tags(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		for tag in [b"a", b"b", b"c", b"d"] {
+			assert_ok!(Task::register_tag(Origin::root(), tag.to_vec()));
+		}
+
+		assert_noop!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, vec![b"x".to_vec()]), Error::<Test>::UnknownTag);
+		assert_noop!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, vec![b"a".to_vec(), b"a".to_vec()]), Error::<Test>::DuplicateTag);
+		let too_many = vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec(), b"d".to_vec()];
+		assert_noop!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, too_many), Error::<Test>::ExceedMaxTagsPerTask);
+
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, vec![b"a".to_vec(), b"b".to_vec()]));
+		let hash = Task::tasks_owned(1)[0];
+		assert_eq!(Task::tasks(hash).expect("should found the task").tags.len(), 2);
+	});
+}
+
+#[test]
+fn tag_index_lists_only_open_tasks(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::register_tag(Origin::root(), b"rust".to_vec()));
+		assert_ok!(Task::register_tag(Origin::root(), b"design".to_vec()));
+
+		let tags = vec![b"rust".to_vec(), b"design".to_vec()];
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), [1].to_vec(), 7, DEADLINE, tags.clone()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), [2].to_vec(), 7, DEADLINE, tags.clone()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), [3].to_vec(), 7, DEADLINE + 1, tags));
+		let hash1 = Task::tasks_owned(1)[0];
+		let hash2 = Task::tasks_owned(1)[1];
+		let hash3 = Task::tasks_owned(1)[2];
+		assert_eq!(Task::tasks_by_tag(b"rust".to_vec()).len(), 3);
+		assert_eq!(Task::tasks_by_tag(b"unknown".to_vec()).len(), 0);
+
+		// Ensure closed tasks are removed from the index
+		assign_volunteer(1, 2, hash1);
+		complete_with_deliverable(2, hash1);
+		assert_ok!(Task::accept_task(Origin::signed(1), hash1, 0));
+		assert!(!Task::tasks_by_tag(b"rust".to_vec()).contains(&hash1));
+
+		// Ensure removed tasks are removed from the index
+		assert_ok!(Task::remove_task(Origin::signed(1), hash2));
+		assert!(!Task::tasks_by_tag(b"design".to_vec()).contains(&hash2));
+
+		// Ensure expired tasks are removed from the index
+		assert_eq!(Task::tasks_by_tag(b"rust".to_vec()), vec![hash3]);
+		run_to_block(DEADLINE + 1);
+		assert_eq!(Task::tasks_by_tag(b"rust".to_vec()).len(), 0);
+		assert_eq!(Task::tasks_by_tag(b"design".to_vec()).len(), 0);
+	});
+}
+
+#[test]
+fn initiator_updates_created_task_and_escrow(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+
+		assert_noop!(Task::update_task(Origin::signed(2), hash, TITLE.to_vec(), Vec::new(), 7, DEADLINE), Error::<Test>::OnlyInitiatorUpdatesTask);
+		assert_noop!(Task::update_task(Origin::signed(1), hash, TITLE.to_vec(), Vec::new(), 7, 1), Error::<Test>::DeadlineInPast);
+		assert_noop!(Task::update_task(Origin::signed(1), hash, TITLE.to_vec(), Vec::new(), 1001, DEADLINE), Error::<Test>::InsufficientBalanceToReserve);
+
+		// Ensure a higher budget reserves the difference and keeps the id
+		assert_ok!(Task::update_task(Origin::signed(1), hash, TITLE.to_vec(), [1].to_vec(), 10, DEADLINE + 1));
+		let task = Task::tasks(hash).expect("should found the task");
+		assert_eq!(task.specification.to_vec(), [1].to_vec());
+		assert_eq!(task.deadline, DEADLINE + 1);
+		assert_eq!(Balances::reserved_balance(&1), 10);
+		assert_eq!(Task::expiring_tasks(DEADLINE).len(), 0);
+		assert_eq!(Task::expiring_tasks(DEADLINE + 1).to_vec(), vec![hash]);
+
+		// Ensure a lower budget releases the difference
+		assert_ok!(Task::update_task(Origin::signed(1), hash, TITLE.to_vec(), [1].to_vec(), 4, DEADLINE + 1));
+		assert_eq!(Balances::reserved_balance(&1), 4);
+
+		// Ensure started tasks can no longer be updated
+		assign_volunteer(1, 2, hash);
+		assert_noop!(Task::update_task(Origin::signed(1), hash, TITLE.to_vec(), Vec::new(), 7, DEADLINE), Error::<Test>::TaskAlreadyStarted);
+	});
+}
+
+#[test]
+fn task_keeps_bounded_version_history(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		assert_eq!(Task::task_versions(hash).len(), 1);
+
+		for budget in 1..=3 {
+			run_to_block(System::block_number() + 1);
+			assert_ok!(Task::update_task(Origin::signed(1), hash, TITLE.to_vec(), [budget as u8].to_vec(), budget, DEADLINE));
+		}
+
+		// Ensure only the latest versions are kept
+		let versions = Task::task_versions(hash);
+		assert_eq!(versions.len(), 3);
+		assert_eq!(versions[0].budget, 1);
+		assert_eq!(versions[2].budget, 3);
+		assert_eq!(versions[2].updated_at, System::block_number());
+		assert_eq!(versions[2].specification, BlakeTwo256::hash(&[3]));
+
+		// Ensure the history is removed with the task
+		assert_ok!(Task::remove_task(Origin::signed(1), hash));
+		assert_eq!(Task::task_versions(hash).len(), 0);
+	});
+}
+
+#[test]
+fn volunteer_abandons_task_and_loses_reputation(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::add_reputation(&2));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		assign_volunteer(1, 2, hash);
+		assert_ok!(Task::submit_deliverable(Origin::signed(2), hash, DELIVERABLE.to_vec()));
+
+		assert_noop!(Task::abandon_task(Origin::signed(1), hash), Error::<Test>::OnlyVolunteerAbandonsTask);
+		assert_ok!(Task::abandon_task(Origin::signed(2), hash));
+
+		// Ensure task is back with the initiator and open for applications
+		let task = Task::tasks(hash).expect("should found the task");
+		assert_eq!(task.status, TaskStatus::Created);
+		assert_eq!(task.current_owner, 1);
+		assert_eq!(Task::tasks_owned(1).to_vec(), vec![hash]);
+		assert_eq!(Task::tasks_owned(2).len(), 0);
+		assert_eq!(Task::deliverables(hash).len(), 0);
+		assert_eq!(Balances::reserved_balance(&1), 7);
+
+		// Ensure reputation is reduced without going below zero
+		assert_eq!(Profile::profiles(2).expect("should find the profile").reputation, 0);
+		assign_volunteer(1, 2, hash);
+		assert_ok!(Task::abandon_task(Origin::signed(2), hash));
+		assert_eq!(Profile::profiles(2).expect("should find the profile").reputation, 0);
+	});
+}
+
+#[test]
+fn missed_deadlines_and_lost_disputes_are_penalized(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		assign_volunteer(1, 2, hash);
+		complete_with_deliverable(2, hash);
+		assert_ok!(Task::accept_task(Origin::signed(1), hash, 0));
+
+		// Ensure the volunteer of an expired task missed the deadline
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[1];
+		assign_volunteer(1, 2, hash);
+		run_to_block(DEADLINE);
+		System::assert_has_event(Event::Profile(pallet_profile::Event::ReputationPenalized { who: 2, reason: PenaltyReason::MissedDeadline, penalty: 1 }));
+		assert_eq!(Profile::profiles(2).expect("should find the profile").reputation, 0);
+
+		// Ensure accounts below the threshold can not take new tasks
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 100, DEADLINE + 10, Vec::new()));
+		let hash = Task::tasks_owned(1)[2];
+		MinReputation::set(1);
+		assert_noop!(Task::apply_for_task(Origin::signed(2), hash, None, H256::zero()), Error::<Test>::ReputationTooLow);
+		MinReputation::set(0);
+
+		// Ensure the party the ruling goes against loses reputation
+		assign_volunteer(1, 2, hash);
+		assert_ok!(Task::raise_dispute(Origin::signed(1), hash));
+		assert_ok!(Task::vote_on_dispute(Origin::signed(20), hash, Percent::from_percent(80)));
+		assert_ok!(Task::vote_on_dispute(Origin::signed(21), hash, Percent::from_percent(80)));
+		System::assert_has_event(Event::Profile(pallet_profile::Event::ReputationPenalized { who: 1, reason: PenaltyReason::LostDispute, penalty: 1 }));
+		assert_eq!(Profile::profiles(1).expect("should find the profile").reputation, 0);
+	});
+}
+
+#[test]
+fn initiator_unassigns_silent_volunteer_after_grace_period(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		assign_volunteer(1, 2, hash);
+
+		// Ensure submitted work restarts the grace period
+		run_to_block(4);
+		assert_ok!(Task::submit_deliverable(Origin::signed(2), hash, DELIVERABLE.to_vec()));
+		run_to_block(8);
+		assert_noop!(Task::unassign_task(Origin::signed(1), hash), Error::<Test>::GracePeriodNotOver);
+		assert_noop!(Task::unassign_task(Origin::signed(2), hash), Error::<Test>::OnlyInitiatorUnassignsTask);
+
+		run_to_block(9);
+		assert_ok!(Task::unassign_task(Origin::signed(1), hash));
+		System::assert_last_event(Event::Task(crate::Event::TaskUnassigned(2, hash)));
+		assert_eq!(Task::tasks(hash).expect("should found the task").status, TaskStatus::Created);
+		assert_eq!(Task::tasks_owned(1).to_vec(), vec![hash]);
+		assert_eq!(Task::tasks_owned(2).len(), 0);
+	});
+}
+
+#[test]
+fn volunteer_proposes_valid_team(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 10, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		let team = vec![(2, Percent::from_percent(50)), (3, Percent::from_percent(50))];
+
+		// Teams can only be formed by the volunteer of a task in progress
+		assert_noop!(Task::propose_team(Origin::signed(2), hash, team.clone()), Error::<Test>::TaskNotStarted);
+		assign_volunteer(1, 2, hash);
+		assert_noop!(Task::propose_team(Origin::signed(3), hash, team.clone()), Error::<Test>::NoPermissionToComplete);
+		assert_noop!(Task::approve_team(Origin::signed(1), hash), Error::<Test>::NoTeamProposed);
+
+		// Ensure the team is led by the volunteer and splits the whole budget
+		assert_noop!(Task::propose_team(Origin::signed(2), hash, vec![(3, Percent::from_percent(100))]), Error::<Test>::TeamMustIncludeVolunteer);
+		assert_noop!(Task::propose_team(Origin::signed(2), hash, vec![(2, Percent::from_percent(50)), (3, Percent::from_percent(40))]), Error::<Test>::InvalidTeamShares);
+		assert_noop!(Task::propose_team(Origin::signed(2), hash, vec![(2, Percent::from_percent(100)), (3, Percent::from_percent(0))]), Error::<Test>::InvalidTeamShares);
+		assert_noop!(Task::propose_team(Origin::signed(2), hash, vec![(2, Percent::from_percent(50)), (2, Percent::from_percent(50))]), Error::<Test>::DuplicateTeamMember);
+		assert_noop!(Task::propose_team(Origin::signed(2), hash, vec![(2, Percent::from_percent(50)), (1, Percent::from_percent(50))]), Error::<Test>::InitiatorCannotJoinTeam);
+		let oversized = vec![
+			(2, Percent::from_percent(25)), (3, Percent::from_percent(25)),
+			(10, Percent::from_percent(25)), (11, Percent::from_percent(25)),
+		];
+		assert_noop!(Task::propose_team(Origin::signed(2), hash, oversized), Error::<Test>::ExceedMaxTeamSize);
+
+		assert_ok!(Task::propose_team(Origin::signed(2), hash, team.clone()));
+		System::assert_last_event(Event::Task(crate::Event::TeamProposed(2, hash)));
+		assert_eq!(Task::proposed_teams(hash).to_vec(), team);
+
+		// Ensure only the initiator approves the team
+		assert_noop!(Task::approve_team(Origin::signed(2), hash), Error::<Test>::OnlyInitiatorApprovesTeam);
+		assert_ok!(Task::approve_team(Origin::signed(1), hash));
+		System::assert_last_event(Event::Task(crate::Event::TeamApproved(1, hash)));
+		assert_eq!(Task::teams(hash).to_vec(), team);
+		assert_eq!(Task::proposed_teams(hash).len(), 0);
+		assert_eq!(Task::tasks_owned(3).to_vec(), vec![hash]);
+	});
+}
+
+#[test]
+fn team_is_paid_and_rewarded_by_share(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(3), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 10, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		assign_volunteer(1, 2, hash);
+		assert_ok!(Task::propose_team(Origin::signed(2), hash, vec![
+			(2, Percent::from_percent(50)), (3, Percent::from_percent(33)), (10, Percent::from_percent(17)),
+		]));
+		assert_ok!(Task::approve_team(Origin::signed(1), hash));
+
+		// Ensure team members hand the task over for review together
+		complete_with_deliverable(2, hash);
+		assert_eq!(Task::tasks_owned(3).len(), 0);
+		assert_ok!(Task::reject_task(Origin::signed(1), hash, H256::zero()));
+		assert_eq!(Task::tasks_owned(3).to_vec(), vec![hash]);
+		assert_ok!(Task::complete_task(Origin::signed(2), hash));
+		assert_ok!(Task::accept_task(Origin::signed(1), hash, 0));
+
+		// Ensure the budget is split by share and fully paid out
+		System::assert_has_event(Event::Task(crate::Event::BudgetPaid(2, hash, 5)));
+		System::assert_has_event(Event::Task(crate::Event::BudgetPaid(3, hash, 3)));
+		System::assert_has_event(Event::Task(crate::Event::BudgetPaid(10, hash, 2)));
+		assert_eq!(Balances::free_balance(&1), 990);
+		assert_eq!(Balances::free_balance(&3), 13);
+		assert_eq!(Balances::free_balance(&10), 1002);
+		assert_eq!(Task::tasks_owned(3).len(), 0);
+
+		// Members with a profile collect reputation
+		assert_eq!(Profile::profiles(2).expect("should find the profile").reputation, 1);
+		assert_eq!(Profile::profiles(3).expect("should find the profile").reputation, 1);
+	});
+}
+
+#[test]
+fn contributors_fund_the_volunteer_or_are_refunded(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let funded = Task::tasks_owned(1)[0];
+		let expiring = Task::tasks_owned(1)[1];
+
+		// Ensure contributions are reserved and merged per funder
+		assert_noop!(Task::fund_task(Origin::signed(3), funded, 0), Error::<Test>::ZeroContribution);
+		assert_noop!(Task::fund_task(Origin::signed(3), funded, 11), Error::<Test>::InsufficientBalanceToReserve);
+		assert_ok!(Task::fund_task(Origin::signed(3), funded, 4));
+		System::assert_last_event(Event::Task(crate::Event::TaskFunded(3, funded, 4)));
+		assert_ok!(Task::fund_task(Origin::signed(10), funded, 10));
+		assert_ok!(Task::fund_task(Origin::signed(10), funded, 10));
+		assert_ok!(Task::fund_task(Origin::signed(2), funded, 1));
+		assert_noop!(Task::fund_task(Origin::signed(1), funded, 1), Error::<Test>::ExceedMaxContributors);
+		assert_eq!(Task::contributions(funded).to_vec(), vec![(3, 4), (10, 20), (2, 1)]);
+		assert_eq!(Balances::reserved_balance(&10), 20);
+
+		// Ensure the whole pool goes to the volunteer once accepted
+		assign_volunteer(1, 2, funded);
+		complete_with_deliverable(2, funded);
+		assert_noop!(Task::fund_task(Origin::signed(3), funded, 1), Error::<Test>::TaskInReview);
+		assert_ok!(Task::accept_task(Origin::signed(1), funded, 0));
+		System::assert_has_event(Event::Task(crate::Event::ContributionsPaid(funded, vec![(3, 4), (10, 20), (2, 1)])));
+		assert_eq!(Balances::free_balance(&2), 1000 - 1 + 7 + 25);
+		assert_eq!(Balances::reserved_balance(&10), 0);
+		assert_eq!(Task::contributions(funded).len(), 0);
+
+		// Ensure contributors are refunded when the task expires
+		assert_ok!(Task::fund_task(Origin::signed(10), expiring, 5));
+		run_to_block(DEADLINE);
+		System::assert_has_event(Event::Task(crate::Event::ContributionRefunded(10, expiring, 5)));
+		assert_eq!(Balances::free_balance(&10), 1000 - 20);
+		assert_eq!(Balances::reserved_balance(&10), 0);
+		assert_eq!(Task::contributions(expiring).len(), 0);
+	});
+}
+
+#[test]
+fn contributors_are_refunded_when_task_is_removed(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		assert_ok!(Task::fund_task(Origin::signed(3), hash, 6));
+		assert_ok!(Task::fund_task(Origin::signed(10), hash, 9));
+
+		assert_ok!(Task::remove_task(Origin::signed(1), hash));
+		System::assert_has_event(Event::Task(crate::Event::ContributionRefunded(3, hash, 6)));
+		System::assert_has_event(Event::Task(crate::Event::ContributionRefunded(10, hash, 9)));
+		assert_eq!(Balances::free_balance(&3), 10);
+		assert_eq!(Balances::free_balance(&10), 1000);
+		assert_eq!(Task::contributions(hash).len(), 0);
+	});
+}
+
+#[test]
+fn payouts_pay_commission_to_treasury(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		Commission::set(Permill::from_percent(10));
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 20, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		assign_volunteer(1, 2, hash);
+		complete_with_deliverable(2, hash);
+
+		// Ensure the commission goes to the treasury and the rest to the volunteer
+		assert_ok!(Task::accept_task(Origin::signed(1), hash, 0));
+		System::assert_has_event(Event::Task(crate::Event::BudgetPaid(2, hash, 18)));
+		System::assert_has_event(Event::Task(crate::Event::CommissionPaid(Task::treasury_account(), hash, 2)));
+		assert_eq!(Balances::free_balance(&Task::treasury_account()), 2);
+		assert_eq!(Balances::free_balance(&2), 1018);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
+#[test]
+fn closed_tasks_keep_a_receipt(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		assert_ok!(Task::fund_task(Origin::signed(10), hash, 3));
+		assign_volunteer(1, 2, hash);
+		complete_with_deliverable(2, hash);
+		assert!(Task::receipts(hash).is_none());
+
+		// Ensure the receipt covers the budget and contributions and outlives the task
+		run_to_block(5);
+		assert_ok!(Task::accept_task(Origin::signed(1), hash, 0));
+		let closed = Task::tasks(hash).expect("should found the task");
+		assert_ok!(Task::remove_task(Origin::signed(1), hash));
+		assert!(Task::tasks(hash).is_none());
+
+		let receipt = Task::receipts(hash).expect("should find the receipt");
+		assert_eq!(receipt.initiator, 1);
+		assert_eq!(receipt.volunteer, 2);
+		assert_eq!(receipt.paid, 10);
+		assert_eq!(receipt.asset, None);
+		assert_eq!(receipt.closed_at, 5);
+		assert_eq!(receipt.task_hash, BlakeTwo256::hash_of(&closed));
+
+		// Ensure receipts are indexed by the accounts involved
+		assert_eq!(Task::receipts_of(&1), vec![(hash, receipt.clone())]);
+		assert_eq!(Task::receipts_of(&2), vec![(hash, receipt)]);
+		assert_eq!(Task::receipts_of(&10).len(), 0);
+	});
+}
+
+#[test]
+fn parties_rate_each_other_once_after_closing(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		let review = H256::repeat_byte(1);
+		assign_volunteer(1, 2, hash);
+		complete_with_deliverable(2, hash);
+		assert_noop!(Task::rate_task(Origin::signed(1), hash, 5, review), Error::<Test>::TaskNotClosed);
+		assert_ok!(Task::accept_task(Origin::signed(1), hash, 0));
+
+		// Ensure only the two parties rate, with a valid score, and only once
+		assert_noop!(Task::rate_task(Origin::signed(3), hash, 5, review), Error::<Test>::NotTaskParty);
+		assert_noop!(Task::rate_task(Origin::signed(1), hash, 0, review), Error::<Test>::InvalidRating);
+		assert_noop!(Task::rate_task(Origin::signed(1), hash, 6, review), Error::<Test>::InvalidRating);
+		assert_ok!(Task::rate_task(Origin::signed(1), hash, 4, review));
+		System::assert_last_event(Event::Task(crate::Event::TaskRated(1, hash, 4)));
+		assert_noop!(Task::rate_task(Origin::signed(1), hash, 5, review), Error::<Test>::AlreadyRated);
+		assert_eq!(Task::ratings(hash, 1).expect("should find the rating").ratee, 2);
+
+		// Ensure ratings are aggregated on the profile of the rated party
+		let ratings = Profile::ratings(2);
+		assert_eq!((ratings.count, ratings.sum, ratings.weighted_average), (1, 4, 400));
+		assert_eq!(Profile::ratings(1).count, 0);
+
+		// Ensure a good rating adds reputation on top of the completed task
+		assert_eq!(Profile::profiles(2).expect("should find the profile").reputation, 2);
+
+		// Ensure the window closes, even after the task is removed
+		assert_ok!(Task::remove_task(Origin::signed(1), hash));
+		run_to_block(System::block_number() + 11);
+		assert_noop!(Task::rate_task(Origin::signed(2), hash, 3, review), Error::<Test>::RatingPeriodOver);
+	});
+}
+
+#[test]
+fn asset_budgets_are_escrowed_in_their_asset(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+		assert_noop!(Task::create_task_with_asset(Origin::signed(1), ASSET_ID, TITLE.to_vec(), Vec::new(), 501, DEADLINE, Vec::new()),
+			Error::<Test>::InsufficientBalanceToReserve);
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		assert_ok!(Task::create_task_with_asset(Origin::signed(1), ASSET_ID, TITLE.to_vec(), Vec::new(), 20, DEADLINE, Vec::new()));
+		assert_ok!(Task::create_task_with_asset(Origin::signed(1), ASSET_ID, TITLE.to_vec(), Vec::new(), 30, DEADLINE, Vec::new()));
+		let native = Task::tasks_owned(1)[0];
+		let paid = Task::tasks_owned(1)[1];
+		let removed = Task::tasks_owned(1)[2];
+		assert_eq!(Task::task_asset(native), None);
+		assert_eq!(Task::task_asset(paid), Some(ASSET_ID));
+
+		// Ensure native budgets are reserved and asset budgets are held by the pallet account
+		assert_eq!(Balances::reserved_balance(&1), 7);
+		assert_eq!(Assets::balance(ASSET_ID, 1), 450);
+		assert_eq!(Assets::balance(ASSET_ID, Task::escrow_account()), 50);
+		assert_ok!(Task::fund_task(Origin::signed(3), removed, 10));
+		assert_eq!(Assets::balance(ASSET_ID, 3), 40);
+		assert_eq!(Balances::free_balance(&3), 10);
+
+		// Ensure each budget is paid out in its own currency
+		for hash in [native, paid].iter() {
+			assign_volunteer(1, 2, *hash);
+			complete_with_deliverable(2, *hash);
+			assert_ok!(Task::accept_task(Origin::signed(1), *hash, 0));
+		}
+		assert_eq!(Balances::free_balance(&2), 1007);
+		assert_eq!(Assets::balance(ASSET_ID, 2), 20);
+
+		// Ensure the initiator and contributors are refunded in the asset
+		assert_ok!(Task::remove_task(Origin::signed(1), removed));
+		assert_eq!(Assets::balance(ASSET_ID, 1), 480);
+		assert_eq!(Assets::balance(ASSET_ID, 3), 50);
+		assert_eq!(Assets::balance(ASSET_ID, Task::escrow_account()), 0);
+		assert_eq!(Task::task_asset(removed), None);
+		assert_eq!(Balances::free_balance(&1), 993);
+	});
+}
+
+#[test]
+fn task_starts_once_prerequisites_are_closed(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Profile::create_profile(Origin::signed(2), USERNAME.to_vec(), Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		let first = Task::tasks_owned(1)[0];
+		let second = Task::tasks_owned(1)[1];
+
+		assert_noop!(Task::set_dependencies(Origin::signed(2), second, None, vec![first]), Error::<Test>::OnlyInitiatorUpdatesTask);
+		assert_ok!(Task::set_dependencies(Origin::signed(1), second, None, vec![first]));
+		System::assert_last_event(Event::Task(crate::Event::DependenciesSet(1, second)));
+
+		// Ensure the dependent task is refused until the prerequisite is closed
+		assert_ok!(Task::apply_for_task(Origin::signed(2), second, None, H256::zero()));
+		assert_noop!(Task::assign_to_applicant(Origin::signed(1), second, 2), Error::<Test>::PrerequisitesNotClosed);
+		assign_volunteer(1, 2, first);
+		complete_with_deliverable(2, first);
+		assert_noop!(Task::assign_to_applicant(Origin::signed(1), second, 2), Error::<Test>::PrerequisitesNotClosed);
+		assert_ok!(Task::accept_task(Origin::signed(1), first, 0));
+		assert_ok!(Task::assign_to_applicant(Origin::signed(1), second, 2));
+
+		// Ensure dependencies are frozen once the task is started
+		assert_noop!(Task::set_dependencies(Origin::signed(1), second, None, Vec::new()), Error::<Test>::TaskAlreadyStarted);
+	});
+}
+
+#[test]
+fn dependencies_form_a_dag(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		for _ in 0..3 {
+			assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		}
+		let (a, b, c) = (Task::tasks_owned(1)[0], Task::tasks_owned(1)[1], Task::tasks_owned(1)[2]);
+
+		assert_ok!(Task::set_dependencies(Origin::signed(1), b, Some(a), vec![a]));
+		assert_ok!(Task::set_dependencies(Origin::signed(1), c, Some(a), vec![b]));
+
+		// Ensure invalid and cyclic dependencies are refused
+		assert_noop!(Task::set_dependencies(Origin::signed(1), a, None, vec![H256::zero()]), Error::<Test>::UnknownDependency);
+		assert_noop!(Task::set_dependencies(Origin::signed(1), a, None, vec![b, b]), Error::<Test>::DuplicateDependency);
+		assert_noop!(Task::set_dependencies(Origin::signed(1), a, None, vec![a]), Error::<Test>::DependencyCycle);
+		assert_noop!(Task::set_dependencies(Origin::signed(1), a, None, vec![c]), Error::<Test>::DependencyCycle);
+		assert_noop!(Task::set_dependencies(Origin::signed(1), a, Some(b), Vec::new()), Error::<Test>::DependencyCycle);
+
+		// Ensure the tree lists every task the last one depends on
+		assert_eq!(Task::dependency_tree(c), vec![(c, vec![b]), (b, vec![a]), (a, Vec::new())]);
+		assert_eq!(Task::dependency_tree(H256::zero()), Vec::new());
+		let mut subtasks = Task::subtasks(a);
+		subtasks.sort();
+		let mut expected = vec![b, c];
+		expected.sort();
+		assert_eq!(subtasks, expected);
+
+		// Ensure removed tasks are detached from the DAG
+		assert_ok!(Task::remove_task(Origin::signed(1), a));
+		assert_eq!(Task::prerequisites(b).len(), 0);
+		assert_eq!(Task::parent_task(b), None);
+		assert_eq!(Task::subtasks(a), Vec::new());
+		assert_eq!(Task::dependency_tree(c), vec![(c, vec![b]), (b, Vec::new())]);
+		assert_eq!(Task::dependents(a).len(), 0);
+	});
+}
+
+#[test]
+fn dependents_and_subtasks_are_bounded(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		for _ in 0..5 {
+			assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		}
+		let owned = Task::tasks_owned(1);
+		let root = owned[0];
+
+		// Ensure a task can't have more dependents and subtasks than allowed
+		for task in owned.iter().skip(1).take(3) {
+			assert_ok!(Task::set_dependencies(Origin::signed(1), *task, Some(root), vec![root]));
+		}
+		assert_noop!(Task::set_dependencies(Origin::signed(1), owned[4], None, vec![root]), Error::<Test>::ExceedMaxDependents);
+		assert_noop!(Task::set_dependencies(Origin::signed(1), owned[4], Some(root), Vec::new()), Error::<Test>::ExceedMaxSubtasks);
+
+		// Ensure moving a task frees its place
+		assert_ok!(Task::set_dependencies(Origin::signed(1), owned[1], None, Vec::new()));
+		assert_ok!(Task::set_dependencies(Origin::signed(1), owned[4], Some(root), vec![root]));
+		assert_eq!(Task::dependents(root).to_vec(), vec![owned[2], owned[3], owned[4]]);
+	});
+}
+
+#[test]
+fn migration_rekeys_tasks_by_nonce(){
+	new_test_ext().execute_with( || {
+
+		// Store a task the way it was stored before storage version 1, keyed by the hash of the task
+		// and including the duplicate id left behind by colliding tasks
+		let legacy = legacy_task(TITLE.to_vec(), 1, 7, 77);
+		let legacy_id = BlakeTwo256::hash(&legacy);
+		unhashed::put_raw(&crate::pallet::Tasks::<Test>::hashed_key_for(legacy_id), &legacy);
+		crate::pallet::TasksOwned::<Test>::insert(1, BoundedVec::try_from(vec![legacy_id, legacy_id]).unwrap());
+		StorageVersion::new(0).put::<Task>();
+
+		assert!(Task::migrate_task_ids() > 0);
+
+		// Ensure the task is moved as it is and its owner points at the nonce based id
+		let new_id = BlakeTwo256::hash_of(&(1u64, 0u64));
+		assert_eq!(unhashed::get_raw(&crate::pallet::Tasks::<Test>::hashed_key_for(legacy_id)), None);
+		assert_eq!(unhashed::get_raw(&crate::pallet::Tasks::<Test>::hashed_key_for(new_id)), Some(legacy));
+		assert_eq!(Task::tasks_owned(1).to_vec(), vec![new_id]);
+		assert_eq!(Task::legacy_task_ids(legacy_id), Some(new_id));
+		assert_eq!(Task::task_nonce(), 1);
+		assert_eq!(Task::on_chain_storage_version(), 1);
+
+		// Ensure the migration only runs once and the task decodes after the next one
+		assert_eq!(Task::migrate_task_ids(), 0);
+		assert!(Task::migrate_bounded_tasks() > 0);
+		let task = Task::tasks(new_id).expect("should found the task");
+		assert_eq!(task.initiator, 1);
+		assert_eq!(task.status, TaskStatus::InProgress);
+		assert_eq!(task.deadline, 77);
+	});
+}
+
+#[test]
+fn migration_escrows_legacy_budgets(){
+	new_test_ext().execute_with( || {
+
+		// Store an open task, an open task its initiator can not fully fund and a closed task
+		let legacy = vec![
+			legacy_task_of(TITLE.to_vec(), 1, 2, TaskStatus::InProgress, 7, 77),
+			legacy_task_of(TITLE.to_vec(), 3, 3, TaskStatus::Created, 50, 77),
+			legacy_task_of(TITLE.to_vec(), 2, 1, TaskStatus::Closed, 20, 77),
+		];
+		let legacy_ids: Vec<H256> = legacy.iter().map(|task| BlakeTwo256::hash(task)).collect();
+		for (legacy_id, task) in legacy_ids.iter().zip(legacy.iter()) {
+			unhashed::put_raw(&crate::pallet::Tasks::<Test>::hashed_key_for(legacy_id), task);
+		}
+		StorageVersion::new(0).put::<Task>();
+
+		assert!(Task::migrate_task_ids() > 0);
+		let new_ids: Vec<H256> = legacy_ids.iter()
+			.map(|legacy_id| Task::legacy_task_ids(legacy_id).expect("should found the new id"))
+			.collect();
+
+		// Ensure the open task is escrowed under its new id
+		assert_eq!(Balances::reserved_balance_named(&new_ids[0], &1), 7);
+
+		// Ensure the budget of the underfunded task is lowered to what could be escrowed
+		assert_eq!(Balances::reserved_balance_named(&new_ids[1], &3), 10);
+		assert_eq!(Balances::free_balance(&3), 0);
+
+		// Ensure the volunteer of the closed task is paid
+		assert_eq!(Balances::free_balance(&2), 980);
+		assert_eq!(Balances::free_balance(&1), 1013);
+
+		// Ensure the tasks decode with the escrowed budgets after the next migration
+		assert!(Task::migrate_bounded_tasks() > 0);
+		assert_eq!(Task::tasks(new_ids[0]).expect("should found the task").budget, 7);
+		assert_eq!(Task::tasks(new_ids[1]).expect("should found the task").budget, 10);
+		assert_eq!(Task::tasks(new_ids[2]).expect("should found the task").status, TaskStatus::Closed);
+	});
+}
+
+#[test]
+fn task_fields_are_bounded(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+
+		// Ensure oversize titles and specifications are refused
+		assert_noop!(Task::create_task(Origin::signed(1), vec![1; 9], Vec::new(), 7, DEADLINE, Vec::new()), Error::<Test>::TitleTooLong);
+		assert_noop!(Task::create_task(Origin::signed(1), TITLE.to_vec(), vec![1; 65], 7, DEADLINE, Vec::new()), Error::<Test>::SpecificationTooLong);
+		assert_ok!(Task::create_task(Origin::signed(1), vec![1; 8], vec![1; 64], 7, DEADLINE, Vec::new()));
+		let hash = Task::tasks_owned(1)[0];
+		assert_noop!(Task::update_task(Origin::signed(1), hash, vec![1; 9], Vec::new(), 7, DEADLINE), Error::<Test>::TitleTooLong);
+	});
+}
+
+#[test]
+fn migration_truncates_oversize_tasks(){
+	new_test_ext().execute_with( || {
+
+		// Store a task with a title longer than allowed since storage version 2
+		let hash = BlakeTwo256::hash_of(&(1u64, 0u64));
+		unhashed::put_raw(&crate::pallet::Tasks::<Test>::hashed_key_for(hash), &legacy_task(vec![9u8; 20], 1, 7, 77));
+		StorageVersion::new(1).put::<Task>();
+
+		assert!(Task::migrate_bounded_tasks() > 0);
+
+		// Ensure the title is truncated, the deadline converted and the rest of the task is kept
+		let migrated = Task::tasks(hash).expect("should found the task");
+		assert_eq!(migrated.title.to_vec(), vec![9u8; 8]);
+		assert_eq!(migrated.initiator, 1);
+		assert_eq!(migrated.budget, 7);
+		assert_eq!(migrated.deadline, 77);
+		assert!(migrated.tags.is_empty());
+		assert_eq!(Task::on_chain_storage_version(), 2);
+	});
+}
+
+#[test]
+fn migration_schedules_expiry_of_legacy_tasks(){
+	new_test_ext().execute_with( || {
+
+		run_to_block(10);
+
+		// Store an overdue task, an open task, a task whose deadline block is full and a closed task
+		let overdue = H256::repeat_byte(1);
+		let open = H256::repeat_byte(2);
+		let crowded = H256::repeat_byte(3);
+		let closed = H256::repeat_byte(4);
+		let legacy = [
+			(overdue, legacy_task_of(TITLE.to_vec(), 1, 2, TaskStatus::InProgress, 7, 5)),
+			(open, legacy_task_of(TITLE.to_vec(), 1, 2, TaskStatus::InProgress, 7, 20)),
+			(crowded, legacy_task_of(TITLE.to_vec(), 1, 1, TaskStatus::Created, 7, 30)),
+			(closed, legacy_task_of(TITLE.to_vec(), 1, 2, TaskStatus::Closed, 7, 20)),
+		];
+		for (hash, task) in legacy.iter() {
+			unhashed::put_raw(&crate::pallet::Tasks::<Test>::hashed_key_for(hash), task);
+		}
+		assert_ok!(Balances::reserve_named(&overdue, &1, 7));
+		let full: Vec<H256> = (0..7).map(|n| H256::repeat_byte(10 + n)).collect();
+		crate::pallet::ExpiringTasks::<Test>::insert(30, BoundedVec::try_from(full).unwrap());
+		StorageVersion::new(1).put::<Task>();
+
+		assert!(Task::migrate_bounded_tasks() > 0);
+
+		// Ensure the overdue task expires at once and its escrow is returned
+		assert_eq!(Task::tasks(overdue).expect("should found the task").status, TaskStatus::Expired);
+		assert_eq!(Balances::reserved_balance_named(&overdue, &1), 0);
+
+		// Ensure open tasks are scheduled, in the next free block when their deadline block is full
+		assert_eq!(Task::expiring_tasks(20).to_vec(), vec![open]);
+		assert_eq!(Task::expiring_tasks(31).to_vec(), vec![crowded]);
+		assert_eq!(Task::tasks(crowded).expect("should found the task").deadline, 31);
+
+		// Ensure the open task expires at its deadline
+		run_to_block(20);
+		assert_eq!(Task::tasks(open).expect("should found the task").status, TaskStatus::Expired);
+		assert_eq!(Task::tasks(closed).expect("should found the task").status, TaskStatus::Closed);
+	});
+}
+
+#[test]
+fn task_statuses_keep_their_encoding(){
+	// Ensure statuses stored before storage version 1 decode to the same status
+	assert_eq!(TaskStatus::Created.encode(), vec![0]);
+	assert_eq!(TaskStatus::InProgress.encode(), vec![1]);
+	assert_eq!(TaskStatus::Closed.encode(), vec![2]);
+}
+
+#[test]
+fn tasks_can_be_queried_in_pages(){
+	new_test_ext().execute_with( || {
+
+		// Profile is necessary for task creation
+		assert_ok!(Profile::create_profile(Origin::signed(1), USERNAME.to_vec(), Vec::new()));
+		for _ in 0..3 {
+			assert_ok!(Task::create_task(Origin::signed(1), TITLE.to_vec(), Vec::new(), 7, DEADLINE, Vec::new()));
+		}
+		let hash = Task::tasks_owned(1)[0];
+		assign_volunteer(1, 2, hash);
+
+		// Ensure listings by owner follow the current owner
+		assert_eq!(Task::tasks_by_owner(1, 0, 10).len(), 2);
+		assert_eq!(Task::tasks_by_owner(1, 1, 1).len(), 1);
+		assert_eq!(Task::tasks_by_owner(1, 2, 1).len(), 0);
+		assert_eq!(Task::tasks_by_owner(2, 0, 10), vec![(hash, Task::tasks(hash).unwrap())]);
+
+		// Ensure listings by initiator and status cover all tasks
+		assert_eq!(Task::tasks_by_initiator(1, 0, 2).len(), 2);
+		assert_eq!(Task::tasks_by_initiator(1, 1, 2).len(), 1);
+		assert_eq!(Task::tasks_by_initiator(2, 0, 10).len(), 0);
+		assert_eq!(Task::tasks_by_status(TaskStatus::Created, 0, 10).len(), 2);
+		assert_eq!(Task::tasks_by_status(TaskStatus::InProgress, 0, 10), vec![(hash, Task::tasks(hash).unwrap())]);
+
+		assert_eq!(Task::task_details(hash), Task::tasks(hash));
+		assert_eq!(Task::task_details(H256::zero()), None);
+	});
+}